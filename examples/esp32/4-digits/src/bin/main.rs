@@ -90,8 +90,8 @@ fn main() -> ! {
                 .clock()
                 .hour(23)
                 .minute(minute)
+                .colon(colon)
                 .finish()
-                .set_dot(1, colon)
                 .display()
                 .ok();
 
@@ -174,9 +174,12 @@ fn main() -> ! {
     tm.clear().ok();
 
     // Loading animation.
-    for _ in 0..8 {
-        tm.circles().rotating().delay_ms(100).finish().run();
-    }
+    tm.circles()
+        .rotating()
+        .delay_ms(100)
+        .repeat(8)
+        .finish()
+        .run();
 
     delay.delay_millis(DELAY_MS);
 
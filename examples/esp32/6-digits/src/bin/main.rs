@@ -40,14 +40,14 @@ fn main() -> ! {
     tm.init().ok();
 
     // Display the number `123456` starting from the first position on the display.
-    // 6-digit displays are a little bit tricky to align. Use the High-Level API for a more convenient way.
+    // The bytes are ordered from left to right, the board profile maps them to the grids of the display.
     let bytes = [
-        0b01001111, /* 3 */
-        0b01011011, /* 2 */
         0b00000110, /* 1 */
-        0b01111101, /* 6 */
-        0b01101101, /* 5 */
+        0b01011011, /* 2 */
+        0b01001111, /* 3 */
         0b01100110, /* 4 */
+        0b01101101, /* 5 */
+        0b01111101, /* 6 */
     ];
     tm.display_slice(0, &bytes).ok();
 
@@ -55,12 +55,12 @@ fn main() -> ! {
 
     // Use the `mappings` module to display the sequence `AC2bS?`.
     let bytes = [
-        DigitBits::Two as u8,
-        UpCharBits::UpC as u8,
         UpCharBits::UpA as u8,
-        SpecialCharBits::QuestionMark as u8,
+        UpCharBits::UpC as u8,
+        DigitBits::Two as u8,
         LoCharBits::LoB as u8,
         UpCharBits::UpS as u8,
+        SpecialCharBits::QuestionMark as u8,
     ];
     tm.display_slice(0, &bytes).ok();
 
@@ -179,9 +179,12 @@ fn main() -> ! {
     tm.clear().ok();
 
     // Loading animation.
-    for _ in 0..8 {
-        tm.circles().rotating().delay_ms(100).finish().run();
-    }
+    tm.circles()
+        .rotating()
+        .delay_ms(100)
+        .repeat(8)
+        .finish()
+        .run();
 
     delay.delay_millis(DELAY_MS);
 
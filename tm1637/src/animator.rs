@@ -0,0 +1,259 @@
+//! Tick-driven animations.
+
+use ::core::marker::PhantomData;
+
+use crate::{layers::reached, step::Step};
+
+/// Tick-driven animation for superloop firmware.
+///
+/// Stores the state of an animation, e.g. the scroll windows, the blink phase or the spinner frame,
+/// and writes the next frame only when it is due, without waiting for the delay between the frames.
+/// Call [`Animator::poll`] with the current time in milliseconds from your main loop.
+///
+/// Created using the `animator` method of the animations, e.g. of the [`Scroller`](crate::options::Scroller).
+/// The device is released, so it can be used for other things between the frames.
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// let mut animator = tm
+///     .options()
+///     .str("HELLO ")
+///     .scroll()
+///     .delay_ms(300)
+///     .forever()
+///     .finish()
+///     .animator();
+///
+/// # let mut now_ms = 0;
+/// # while now_ms < 3000 {
+/// // In the main loop
+/// animator.poll(now_ms, &mut tm).ok();
+///
+/// // Do other work
+/// # now_ms += 10;
+/// # }
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Animator<const N: usize, T, I> {
+    steps: I,
+    /// Point in time in milliseconds at which the next step is due or [`None`] if it is due immediately.
+    due_ms: Option<u32>,
+    finished: bool,
+    _token: PhantomData<T>,
+}
+
+impl<const N: usize, T, I> Animator<N, T, I> {
+    /// Create a new [`Animator`] from the `steps` of an animation and their delays.
+    pub(crate) const fn new(steps: I) -> Self {
+        Self {
+            steps,
+            due_ms: None,
+            finished: false,
+            _token: PhantomData,
+        }
+    }
+
+    /// Returns `true` if all steps were executed.
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Get the milliseconds from `now_ms` until the next frame is due.
+    ///
+    /// Returns [`None`] if the animation is finished.
+    pub const fn deadline(&self, now_ms: u32) -> Option<u32> {
+        if self.finished {
+            return None;
+        }
+
+        match self.due_ms {
+            Some(due_ms) if !reached(now_ms, due_ms) => Some(due_ms.wrapping_sub(now_ms)),
+            _ => Some(0),
+        }
+    }
+
+    /// Get the next step if it is due at `now_ms`.
+    fn next<S>(&mut self, now_ms: u32) -> Option<Step<S>>
+    where
+        I: Iterator<Item = (Step<S>, u32)>,
+    {
+        if self.finished {
+            return None;
+        }
+
+        if let Some(due_ms) = self.due_ms {
+            if !reached(now_ms, due_ms) {
+                return None;
+            }
+        }
+
+        match self.steps.next() {
+            Some((step, delay_ms)) => {
+                self.due_ms = Some(now_ms.wrapping_add(delay_ms));
+
+                Some(step)
+            }
+            None => {
+                self.finished = true;
+
+                None
+            }
+        }
+    }
+}
+
+#[::duplicate::duplicate_item(
+    module        async     await               Token                     DelayTrait;
+    [asynch]      [async]   [await.identity()]  [crate::tokens::Async]    [::embedded_hal_async::delay::DelayNs];
+    [blocking]    []        [identity()]        [crate::tokens::Blocking] [::embedded_hal::delay::DelayNs];
+)]
+mod module {
+    use ::embedded_hal::digital::OutputPin;
+
+    use super::Animator;
+    use crate::{step::Step, ConditionalInputPin, Error, Identity, TM1637};
+
+    impl<const N: usize, I, S> Animator<N, Token, I>
+    where
+        I: Iterator<Item = (Step<S>, u32)>,
+        S: Iterator<Item = u8>,
+    {
+        /// Execute the next step if it is due at `now_ms` and return `true` if a step was executed.
+        ///
+        /// The delay of a step starts when it is executed. At most one step is executed per call,
+        /// so frames are never written in bursts to catch up with a late call
+        /// and animations with zero delays do not block the main loop.
+        /// Steps with a zero delay are due at once, see [`Animator::deadline`].
+        ///
+        /// ## Note
+        ///
+        /// - The animation continues on error. The failed step is not retried.
+        pub async fn poll<CLK, DIO, DELAY, ERR>(
+            &mut self,
+            now_ms: u32,
+            device: &mut TM1637<N, Token, CLK, DIO, DELAY>,
+        ) -> Result<bool, Error<ERR>>
+        where
+            CLK: OutputPin<Error = ERR>,
+            DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
+            DELAY: DelayTrait,
+        {
+            match self.next(now_ms) {
+                Some(step) => device.execute(step).await.map(|_| true),
+                None => Ok(false),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::core::cell::Cell;
+
+    use super::Animator;
+    use crate::{mock::Noop, tokens::Blocking, TM1637Builder};
+
+    /// Count the steps taken from the `animator`, so the tests do not depend on the result of the writes.
+    fn counted<'a, I: Iterator + 'a>(
+        animator: Animator<4, Blocking, I>,
+        count: &'a Cell<usize>,
+    ) -> Animator<4, Blocking, impl Iterator<Item = I::Item> + 'a> {
+        Animator::new(animator.steps.inspect(|_| count.set(count.get() + 1)))
+    }
+
+    #[test]
+    fn frames_are_written_when_due() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+        let count = Cell::new(0);
+
+        let mut animator = counted(
+            tm.options()
+                .str("123456")
+                .scroll()
+                .linear()
+                .delay_ms(100)
+                .finish()
+                .animator(),
+            &count,
+        );
+
+        animator.poll(0, &mut tm).ok();
+        assert_eq!(count.get(), 1);
+
+        animator.poll(99, &mut tm).ok();
+        assert_eq!(count.get(), 1);
+        assert_eq!(animator.deadline(99), Some(1));
+
+        // A late poll writes a single frame.
+        animator.poll(250, &mut tm).ok();
+        assert_eq!(count.get(), 2);
+
+        animator.poll(350, &mut tm).ok();
+        assert_eq!(count.get(), 3);
+        assert!(!animator.is_finished());
+
+        animator.poll(450, &mut tm).ok();
+        assert_eq!(count.get(), 3);
+        assert!(animator.is_finished());
+        assert_eq!(animator.deadline(450), None);
+    }
+
+    #[test]
+    fn zero_delays_are_due_at_once() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+        let count = Cell::new(0);
+
+        let mut animator = counted(
+            tm.options()
+                .str("1234")
+                .blink()
+                .on_ms(0)
+                .off_ms(0)
+                .times(2)
+                .finish()
+                .animator(),
+            &count,
+        );
+
+        // With the `ack` feature, every write fails and the animation still continues.
+        for executed in 1..=6 {
+            animator.poll(0, &mut tm).ok();
+            assert_eq!(count.get(), executed);
+            assert_eq!(animator.deadline(0), Some(0));
+        }
+
+        animator.poll(0, &mut tm).ok();
+        assert_eq!(count.get(), 6);
+        assert!(animator.is_finished());
+    }
+
+    #[test]
+    fn endless_zero_delays_do_not_block() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+        let count = Cell::new(0);
+
+        let mut animator = counted(
+            tm.options()
+                .str("123456")
+                .scroll()
+                .delay_ms(0)
+                .forever()
+                .finish()
+                .animator(),
+            &count,
+        );
+
+        for executed in 1..=100 {
+            animator.poll(0, &mut tm).ok();
+            assert_eq!(count.get(), executed);
+        }
+
+        assert!(!animator.is_finished());
+    }
+}
@@ -0,0 +1,301 @@
+//! Physical properties of the display module.
+
+use crate::mappings::SegmentBits;
+
+/// What the dot segment of a digit drives on the display module.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DotKind {
+    /// A decimal point after the digit.
+    #[default]
+    Decimal,
+    /// A colon between the digit and the following one.
+    Colon,
+    /// Nothing is connected.
+    None,
+}
+
+/// Describes the physical display module.
+///
+/// Set using [`TM1637Builder::profile`](crate::TM1637Builder::profile) or [`TM1637::set_profile`](crate::TM1637::set_profile)
+/// and applied to every write to the display.
+///
+/// Defaults to [`BoardProfile::DECIMAL`] for 4-digit displays and [`BoardProfile::SIX_DIGIT`] for 6-digit displays.
+///
+/// ## Wiring
+///
+/// The high-level APIs and the formatters produce the digits from left to right.
+/// The profile alone maps each digit to the grid, i.e. the address, driving it, see [`BoardProfile::with_grids`].
+///
+/// ## Dots
+///
+/// Decimal points and colons are driven by the [`SegmentBits::Dot`] bit of a digit.
+/// [`StrParser`](crate::str::StrParser) parses both `.` and `:` into this bit, so `"12:34"` lights the colon of a clock module.
+/// [`DisplayOptions::str`](crate::options::DisplayOptions::str) drops the decimal points that would end up on a [`DotKind::Colon`] digit,
+/// so `"23.5"` is shown as `235` instead of `23:5`.
+///
+/// The dot bit is cleared for digits without a [`DotKind::Decimal`] or [`DotKind::Colon`],
+/// so dots that end up on such a digit, e.g. the dot of the last digit after [`DisplayOptions::flip`](crate::options::DisplayOptions::flip), are dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, BoardProfile, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop)
+///     .profile(BoardProfile::CLOCK)
+///     .build_blocking::<4>();
+///
+/// tm.options().str("12:34").display().ok();
+/// ```
+///
+/// Describe a 4-digit board with the grids wired from right to left and decimal points on the first three digits.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{BoardProfile, DotKind};
+///
+/// let profile = BoardProfile::new(4)
+///     .with_grids([3, 2, 1, 0, 4, 5])
+///     .with_dot(3, DotKind::None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BoardProfile {
+    digits: usize,
+    grids: [usize; 6],
+    dots: [DotKind; 6],
+}
+
+impl BoardProfile {
+    /// The common red 4-digit clock module with a colon after the second digit and no decimal points.
+    pub const CLOCK: Self = Self::new(4)
+        .with_dots([DotKind::None; 6])
+        .with_dot(1, DotKind::Colon);
+
+    /// The 4-digit module with a decimal point after every digit.
+    pub const DECIMAL: Self = Self::new(4);
+
+    /// The 6-digit module with a decimal point after every digit.
+    ///
+    /// The grids are wired in the `2-1-0-5-4-3` order.
+    pub const SIX_DIGIT: Self = Self::new(6).with_grids([2, 1, 0, 5, 4, 3]);
+
+    /// Create a new [`BoardProfile`] for a display with the given number of `digits`.
+    ///
+    /// The grids are wired from left to right and every digit has a decimal point.
+    pub const fn new(digits: usize) -> Self {
+        Self {
+            digits,
+            grids: [0, 1, 2, 3, 4, 5],
+            dots: [DotKind::Decimal; 6],
+        }
+    }
+
+    /// Get the default profile for a display with the given number of `digits`.
+    pub const fn for_digits(digits: usize) -> Self {
+        match digits {
+            6 => Self::SIX_DIGIT,
+            _ => Self::DECIMAL,
+        }
+    }
+
+    /// Set the grid, i.e. the address, driving each digit from left to right.
+    ///
+    /// # Panics
+    ///
+    /// If the addresses from `0` to `5` do not appear exactly once.
+    /// The panic happens at compile time if the profile is a `const`.
+    pub const fn with_grids(mut self, grids: [usize; 6]) -> Self {
+        let mut seen = [false; 6];
+        let mut position = 0;
+
+        while position < 6 {
+            let address = grids[position];
+
+            assert!(
+                address < 6 && !seen[address],
+                "every address from 0 to 5 must appear exactly once"
+            );
+
+            seen[address] = true;
+            position += 1;
+        }
+
+        self.grids = grids;
+        self
+    }
+
+    /// Set what the dot segment of each digit drives, from left to right.
+    pub const fn with_dots(mut self, dots: [DotKind; 6]) -> Self {
+        self.dots = dots;
+        self
+    }
+
+    /// Set what the dot segment of the digit at `position` drives.
+    pub const fn with_dot(mut self, position: usize, dot: DotKind) -> Self {
+        if position < 6 {
+            self.dots[position] = dot;
+        }
+
+        self
+    }
+
+    /// Get the number of digits.
+    pub const fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// Get the address driving the digit at `position`.
+    pub const fn address(&self, position: usize) -> usize {
+        self.grids[position % 6]
+    }
+
+    /// Get the position of the digit driven by the `address`.
+    pub const fn position(&self, address: usize) -> usize {
+        let address = address % 6;
+        let mut position = 0;
+
+        while position < 6 {
+            if self.grids[position] == address {
+                return position;
+            }
+
+            position += 1;
+        }
+
+        address
+    }
+
+    /// Get what the dot segment of the digit at `position` drives.
+    pub const fn dot(&self, position: usize) -> DotKind {
+        if position < self.digits && position < 6 {
+            return self.dots[position];
+        }
+
+        DotKind::None
+    }
+
+    /// Get the position of the first digit driving a colon.
+    pub const fn colon(&self) -> Option<usize> {
+        let mut position = 0;
+
+        while position < 6 {
+            if let DotKind::Colon = self.dot(position) {
+                return Some(position);
+            }
+
+            position += 1;
+        }
+
+        None
+    }
+
+    /// Maps the `digits` of a display with `N` positions, from left to right, to the addresses driving them.
+    ///
+    /// The dots are filtered, see [`BoardProfile::filter`].
+    pub(crate) fn addresses<const N: usize>(&self, digits: [Option<u8>; N]) -> [Option<u8>; 6] {
+        let mut addresses = [None; 6];
+
+        for (position, byte) in digits.into_iter().enumerate().take(6) {
+            if let Some(byte) = byte {
+                addresses[self.address(position)] = Some(self.filter(position, byte));
+            }
+        }
+
+        addresses
+    }
+
+    /// Clear the dot of the `byte` shown at `position` if the digit has no dot.
+    pub(crate) const fn filter(&self, position: usize, byte: u8) -> u8 {
+        match self.dot(position) {
+            DotKind::None => byte & !(SegmentBits::Dot as u8),
+            _ => byte,
+        }
+    }
+}
+
+impl Default for BoardProfile {
+    fn default() -> Self {
+        Self::DECIMAL
+    }
+}
+
+/// Collects the `bytes` written at `position` into the digits they show on a display with `N` positions, from left to right.
+pub(crate) fn digits<const N: usize>(
+    position: usize,
+    bytes: impl Iterator<Item = u8>,
+) -> [Option<u8>; N] {
+    let mut digits = [None; N];
+
+    for (digit, byte) in digits.iter_mut().skip(position).zip(bytes) {
+        *digit = Some(byte);
+    }
+
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mappings::DigitBits;
+
+    use super::*;
+
+    const DOT: u8 = SegmentBits::Dot as u8;
+
+    #[test]
+    fn clock_keeps_the_colon_only() {
+        let two = DigitBits::Two as u8;
+
+        assert_eq!(BoardProfile::CLOCK.colon(), Some(1));
+        assert_eq!(BoardProfile::CLOCK.filter(1, two | DOT), two | DOT);
+        assert_eq!(BoardProfile::CLOCK.filter(2, two | DOT), two);
+        assert_eq!(BoardProfile::DECIMAL.filter(2, two | DOT), two | DOT);
+        // Digits past the end have no dots.
+        assert_eq!(BoardProfile::DECIMAL.filter(4, two | DOT), two);
+    }
+
+    #[test]
+    fn grids_map_addresses_to_positions() {
+        let profile = BoardProfile::SIX_DIGIT;
+
+        assert_eq!(profile.address(0), 2);
+        assert_eq!(profile.position(2), 0);
+        assert_eq!(profile.position(3), 5);
+        // Addresses wrap around.
+        assert_eq!(profile.position(8), 0);
+    }
+
+    #[test]
+    fn digits_are_mapped_to_addresses() {
+        let six = BoardProfile::SIX_DIGIT.addresses([1, 2, 3, 4, 5, 6].map(Some));
+
+        assert_eq!(six, [3, 2, 1, 6, 5, 4].map(Some));
+
+        let four = BoardProfile::CLOCK.addresses([None, Some(2 | DOT), Some(3 | DOT), None]);
+
+        assert_eq!(four, [None, Some(2 | DOT), Some(3), None, None, None]);
+    }
+
+    #[test]
+    #[should_panic(expected = "every address from 0 to 5 must appear exactly once")]
+    fn grids_must_contain_every_address_once() {
+        let _ = BoardProfile::new(4).with_grids([0, 1, 2, 2, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "every address from 0 to 5 must appear exactly once")]
+    fn grids_must_not_exceed_the_addresses() {
+        let _ = BoardProfile::new(4).with_grids([0, 1, 2, 3, 4, 6]);
+    }
+
+    #[test]
+    fn bytes_are_collected_into_digits() {
+        let six = digits::<6>(0, [1, 2, 3, 4, 5, 6].into_iter());
+
+        assert_eq!(six, [Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]);
+
+        let four = digits::<4>(2, [1, 2, 3].into_iter());
+
+        assert_eq!(four, [None, None, Some(1), Some(2)]);
+    }
+}
@@ -10,7 +10,7 @@
 /// - 4: Display state (0 - off, 1 - on)
 /// - 5-7: Base address
 #[repr(u8)]
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Brightness {
     /// Display off.
@@ -33,3 +33,47 @@ pub enum Brightness {
     /// Brightness level 7. Highest brightness.
     L7 = 0b10001111,
 }
+
+impl Brightness {
+    /// Returns all brightness levels from [`Brightness::Off`] to [`Brightness::L7`].
+    pub const fn all() -> [Brightness; 9] {
+        [
+            Brightness::Off,
+            Brightness::L0,
+            Brightness::L1,
+            Brightness::L2,
+            Brightness::L3,
+            Brightness::L4,
+            Brightness::L5,
+            Brightness::L6,
+            Brightness::L7,
+        ]
+    }
+
+    /// Returns the index of the brightness level in [`Brightness::all`].
+    ///
+    /// [`Brightness::Off`] is `0` and [`Brightness::L7`] is `8`.
+    pub const fn level(&self) -> u8 {
+        match self {
+            Brightness::Off => 0,
+            _ => (*self as u8 & 0b0000_0111) + 1,
+        }
+    }
+
+    /// Creates a new [`Brightness`] from its index in [`Brightness::all`].
+    ///
+    /// Levels greater than `8` are clamped to [`Brightness::L7`].
+    pub const fn from_level(level: u8) -> Self {
+        match level {
+            0 => Brightness::Off,
+            1 => Brightness::L0,
+            2 => Brightness::L1,
+            3 => Brightness::L2,
+            4 => Brightness::L3,
+            5 => Brightness::L4,
+            6 => Brightness::L5,
+            7 => Brightness::L6,
+            _ => Brightness::L7,
+        }
+    }
+}
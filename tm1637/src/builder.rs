@@ -1,7 +1,8 @@
 use crate::{
+    delay::BlockingBitDelay,
     mode::Mode,
     tokens::{Async, Blocking},
-    Brightness, TM1637,
+    BoardProfile, Brightness, Orientation, SegmentMap, TM1637,
 };
 
 /// `TM1637` 7-segment display builder.
@@ -13,6 +14,9 @@ pub struct TM1637Builder<CLK, DIO, DELAY> {
     delay: DELAY,
     brightness: Brightness,
     delay_us: u32,
+    orientation: Orientation,
+    profile: Option<BoardProfile>,
+    segments: &'static SegmentMap,
 }
 
 impl<CLK, DIO, DELAY> TM1637Builder<CLK, DIO, DELAY> {
@@ -20,6 +24,9 @@ impl<CLK, DIO, DELAY> TM1637Builder<CLK, DIO, DELAY> {
     ///
     /// - `brightness`: [`Brightness::L0`]
     /// - `delay_us`: 100
+    /// - `orientation`: [`Orientation::Normal`]
+    /// - `profile`: [`BoardProfile::for_digits`] the number of digits of the display
+    /// - `segments`: [`SegmentMap::IDENTITY`]
     pub const fn new(clk: CLK, dio: DIO, delay: DELAY) -> Self {
         Self {
             clk,
@@ -27,6 +34,9 @@ impl<CLK, DIO, DELAY> TM1637Builder<CLK, DIO, DELAY> {
             delay,
             brightness: Brightness::L0,
             delay_us: 100,
+            orientation: Orientation::Normal,
+            profile: None,
+            segments: &SegmentMap::IDENTITY,
         }
     }
 
@@ -42,6 +52,49 @@ impl<CLK, DIO, DELAY> TM1637Builder<CLK, DIO, DELAY> {
         self
     }
 
+    /// Set the orientation.
+    ///
+    /// See [`Orientation`].
+    pub const fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the board profile.
+    ///
+    /// See [`BoardProfile`].
+    pub const fn profile(mut self, profile: BoardProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Set the segment wiring.
+    ///
+    /// See [`SegmentMap`].
+    pub const fn segments(mut self, segments: &'static SegmentMap) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Bit-bang using the blocking `bits` delay and await the delay given in [`TM1637Builder::new`] only between frames.
+    ///
+    /// Only useful in `async` mode, see [`BlockingBitDelay`].
+    pub fn blocking_bit_delay<B>(
+        self,
+        bits: B,
+    ) -> TM1637Builder<CLK, DIO, BlockingBitDelay<B, DELAY>> {
+        TM1637Builder {
+            clk: self.clk,
+            dio: self.dio,
+            delay: BlockingBitDelay::new(bits, self.delay),
+            brightness: self.brightness,
+            delay_us: self.delay_us,
+            orientation: self.orientation,
+            profile: self.profile,
+            segments: self.segments,
+        }
+    }
+
     /// Build a [`TM1637`] instance with the specified mode.
     ///
     /// ## Async
@@ -68,13 +121,22 @@ impl<CLK, DIO, DELAY> TM1637Builder<CLK, DIO, DELAY> {
     /// let tm = TM1637Builder::new(clk, dio, delay).build::<4, Blocking>();
     /// ```
     pub fn build<const N: usize, T: Mode>(self) -> TM1637<N, T, CLK, DIO, DELAY> {
-        TM1637::new(
+        let mut tm = TM1637::new(
             self.clk,
             self.dio,
             self.delay,
             self.brightness,
             self.delay_us,
-        )
+        );
+
+        tm.set_orientation(self.orientation);
+        tm.set_segments(self.segments);
+
+        if let Some(profile) = self.profile {
+            tm.set_profile(profile);
+        }
+
+        tm
     }
 
     /// Build an async [`TM1637`] instance.
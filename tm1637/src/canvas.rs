@@ -0,0 +1,186 @@
+//! Segment-level drawing.
+//!
+//! # Example
+//!
+//! Draw a border around a 4-digit display.
+//!
+//! ```
+//! use tm1637_embedded_hal::{canvas::Canvas, mock::Noop, TM1637Builder};
+//!
+//! let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+//!
+//! let canvas = Canvas::<4>::new().border();
+//!
+//! tm.options().iter(canvas.into_iter()).display().ok();
+//! ```
+//!
+//! The display will show:
+//!
+//! ```text
+//!  ---   ---   ---   ---
+//! |                       |
+//!
+//! |                       |
+//!  ---   ---   ---   ---
+//! ```
+
+use crate::mappings::SegmentBits;
+
+/// Horizontal line of segments across all digits.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Row {
+    /// Segment `A`.
+    #[default]
+    Top,
+    /// Segment `G`.
+    Middle,
+    /// Segment `D`.
+    Bottom,
+}
+
+impl Row {
+    /// Returns the segment of the row.
+    pub const fn segment(&self) -> SegmentBits {
+        match self {
+            Row::Top => SegmentBits::SegA,
+            Row::Middle => SegmentBits::SegG,
+            Row::Bottom => SegmentBits::SegD,
+        }
+    }
+}
+
+/// A canvas of `N` digits to draw single segments on.
+///
+/// Columns are the vertical segment pairs of the digits from left to right.
+/// Digit `d` has the left column `2 * d` (`F/E`) and the right column `2 * d + 1` (`B/C`).
+///
+/// Drawing outside of the canvas is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Canvas<const N: usize> {
+    bytes: [u8; N],
+}
+
+impl<const N: usize> Default for Canvas<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Canvas<N> {
+    /// Create a new empty [`Canvas`].
+    pub const fn new() -> Self {
+        Self { bytes: [0; N] }
+    }
+
+    /// Create a new [`Canvas`] from the given `bytes`.
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes of the canvas.
+    pub const fn bytes(&self) -> [u8; N] {
+        self.bytes
+    }
+
+    /// Returns `true` if the `segment` of the `digit` is set.
+    pub const fn is_set(&self, digit: usize, segment: SegmentBits) -> bool {
+        digit < N && self.bytes[digit] & segment as u8 != 0
+    }
+
+    /// Set the `segment` of the `digit`.
+    pub const fn set(mut self, digit: usize, segment: SegmentBits) -> Self {
+        if digit < N {
+            self.bytes[digit] |= segment as u8;
+        }
+
+        self
+    }
+
+    /// Clear the `segment` of the `digit`.
+    pub const fn clear(mut self, digit: usize, segment: SegmentBits) -> Self {
+        if digit < N {
+            self.bytes[digit] &= !(segment as u8);
+        }
+
+        self
+    }
+
+    /// Clear all segments.
+    pub const fn clear_all(self) -> Self {
+        Self::new()
+    }
+
+    /// Draw a horizontal line across all digits.
+    pub const fn line_horizontal(mut self, row: Row) -> Self {
+        let mut digit = 0;
+
+        while digit < N {
+            self = self.set(digit, row.segment());
+            digit += 1;
+        }
+
+        self
+    }
+
+    /// Draw a vertical line on the given `column`.
+    pub const fn line_vertical(self, column: usize) -> Self {
+        let digit = column / 2;
+
+        match column % 2 {
+            0 => self
+                .set(digit, SegmentBits::SegF)
+                .set(digit, SegmentBits::SegE),
+            _ => self
+                .set(digit, SegmentBits::SegB)
+                .set(digit, SegmentBits::SegC),
+        }
+    }
+
+    /// Draw a border around the display.
+    pub const fn border(self) -> Self {
+        self.line_horizontal(Row::Top)
+            .line_horizontal(Row::Bottom)
+            .line_vertical(0)
+            .line_vertical((2 * N).saturating_sub(1))
+    }
+}
+
+impl<const N: usize> IntoIterator for Canvas<N> {
+    type Item = u8;
+    type IntoIter = ::core::array::IntoIter<u8, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bytes.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_clear() {
+        let canvas = Canvas::<4>::new()
+            .set(1, SegmentBits::SegA)
+            .set(1, SegmentBits::Dot)
+            .set(4, SegmentBits::SegA)
+            .clear(1, SegmentBits::SegA);
+
+        assert!(canvas.is_set(1, SegmentBits::Dot));
+        assert!(!canvas.is_set(1, SegmentBits::SegA));
+        assert_eq!(canvas.bytes(), [0, SegmentBits::Dot as u8, 0, 0]);
+    }
+
+    #[test]
+    fn border() {
+        let canvas = Canvas::<4>::new().border();
+
+        let a_d = SegmentBits::SegA as u8 | SegmentBits::SegD as u8;
+        let e_f = SegmentBits::SegE as u8 | SegmentBits::SegF as u8;
+        let b_c = SegmentBits::SegB as u8 | SegmentBits::SegC as u8;
+
+        assert_eq!(canvas.bytes(), [a_d | e_f, a_d, a_d, a_d | b_c]);
+    }
+}
@@ -0,0 +1,250 @@
+//! Delay strategies for the `async` mode.
+
+use ::core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Bit-bangs with a blocking delay and only awaits the delays between frames.
+///
+/// In `async` mode, every bit of a transaction awaits [`TM1637::delay_us`](crate::TM1637::delay_us) several times,
+/// waking up the executor for each of them.
+/// Using this delay, the bit delays busy-wait using the blocking `bits` delay,
+/// and only yield to the executor between transactions, see [`BlockingBitDelay::yield_after_us`] to yield within them.
+/// The delays between the frames of animations await the `frames` delay.
+///
+/// Set using [`TM1637Builder::blocking_bit_delay`](crate::TM1637Builder::blocking_bit_delay).
+/// Consider lowering [`TM1637Builder::delay_us`](crate::TM1637Builder::delay_us), since the executor is blocked during a transaction.
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+///
+/// let async_delay = Noop;
+/// let blocking_delay = Noop;
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, async_delay)
+///     .blocking_bit_delay(blocking_delay)
+///     .delay_us(10)
+///     .build_async::<4>();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BlockingBitDelay<B, A> {
+    bits: B,
+    frames: A,
+    /// Microseconds to block before yielding within a transaction.
+    yield_us: Option<u32>,
+    /// Microseconds blocked since the last yield.
+    blocked_us: u32,
+}
+
+impl<B, A> BlockingBitDelay<B, A> {
+    /// Create a new [`BlockingBitDelay`] from the blocking `bits` delay and the async `frames` delay.
+    ///
+    /// Yields to the executor only between transactions.
+    pub const fn new(bits: B, frames: A) -> Self {
+        Self {
+            bits,
+            frames,
+            yield_us: None,
+            blocked_us: 0,
+        }
+    }
+
+    /// Additionally yield to the executor within a transaction once the bit delays blocked for `us` microseconds in total.
+    ///
+    /// `0` yields after every bit delay. Use this for long transactions at high bit delays,
+    /// the display accepts pauses within a transaction.
+    pub const fn yield_after_us(mut self, us: u32) -> Self {
+        self.yield_us = Some(us);
+        self
+    }
+
+    /// Count the blocked `us` and yield to the executor once the budget is used up.
+    async fn blocked(&mut self, us: u32) {
+        let Some(yield_us) = self.yield_us else {
+            return;
+        };
+
+        self.blocked_us = self.blocked_us.saturating_add(us);
+
+        if self.blocked_us >= yield_us {
+            self.blocked_us = 0;
+
+            yield_now().await;
+        }
+    }
+
+    /// Get a reference to the blocking delay used between bits.
+    pub const fn bits(&self) -> &B {
+        &self.bits
+    }
+
+    /// Get a reference to the async delay used between frames.
+    pub const fn frames(&self) -> &A {
+        &self.frames
+    }
+
+    /// Split the [`BlockingBitDelay`] into its parts.
+    pub fn into_parts(self) -> (B, A) {
+        (self.bits, self.frames)
+    }
+}
+
+impl<B, A> ::embedded_hal_async::delay::DelayNs for BlockingBitDelay<B, A>
+where
+    B: ::embedded_hal::delay::DelayNs,
+    A: ::embedded_hal_async::delay::DelayNs,
+{
+    /// A zero delay marks the end of a transaction and yields to the executor.
+    async fn delay_ns(&mut self, ns: u32) {
+        if ns == 0 {
+            self.blocked_us = 0;
+
+            return yield_now().await;
+        }
+
+        self.bits.delay_ns(ns);
+        self.blocked(ns / 1000).await;
+    }
+
+    async fn delay_us(&mut self, us: u32) {
+        self.bits.delay_us(us);
+        self.blocked(us).await;
+    }
+
+    async fn delay_ms(&mut self, ms: u32) {
+        self.frames.delay_ms(ms).await;
+    }
+}
+
+/// Yields to the executor once.
+const fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+/// Future returned by [`yield_now`].
+#[derive(Debug)]
+struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::core::pin::pin;
+    use ::embedded_hal_async::delay::DelayNs;
+    use ::futures::task::noop_waker_ref;
+
+    use crate::{mock::Noop, TM1637Builder};
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Count(u32);
+
+    impl ::embedded_hal::delay::DelayNs for Count {
+        fn delay_ns(&mut self, ns: u32) {
+            self.0 += ns;
+        }
+    }
+
+    impl ::embedded_hal_async::delay::DelayNs for Count {
+        async fn delay_ns(&mut self, ns: u32) {
+            yield_now().await;
+
+            self.0 += ns;
+        }
+    }
+
+    fn poll<F: Future>(future: F) -> Poll<F::Output> {
+        let mut future = pin!(future);
+
+        future
+            .as_mut()
+            .poll(&mut Context::from_waker(noop_waker_ref()))
+    }
+
+    #[test]
+    fn bits_are_delayed_without_yielding() {
+        let mut delay = BlockingBitDelay::new(Count::default(), Count::default());
+
+        for _ in 0..100 {
+            assert_eq!(poll(delay.delay_us(100)), Poll::Ready(()));
+        }
+
+        assert_eq!(delay.bits().0, 10_000_000);
+
+        // The end of a transaction yields once.
+        assert_eq!(poll(delay.delay_ns(0)), Poll::Pending);
+
+        // The frames delay yields once.
+        assert_eq!(poll(delay.delay_ms(1)), Poll::Pending);
+        assert_eq!(delay.frames().0, 0);
+    }
+
+    #[test]
+    fn bits_yield_once_the_budget_is_used_up() {
+        let mut delay =
+            BlockingBitDelay::new(Count::default(), Count::default()).yield_after_us(250);
+
+        assert_eq!(poll(delay.delay_us(100)), Poll::Ready(()));
+        assert_eq!(poll(delay.delay_us(100)), Poll::Ready(()));
+        assert_eq!(poll(delay.delay_us(100)), Poll::Pending);
+        // The budget starts over after yielding.
+        assert_eq!(poll(delay.delay_us(100)), Poll::Ready(()));
+        assert_eq!(poll(delay.delay_us(100)), Poll::Ready(()));
+
+        // And at the end of a transaction.
+        assert_eq!(poll(delay.delay_ns(0)), Poll::Pending);
+        assert_eq!(poll(delay.delay_us(100)), Poll::Ready(()));
+        assert_eq!(poll(delay.delay_us(100)), Poll::Ready(()));
+    }
+
+    #[test]
+    fn writes_yield_between_transactions() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop)
+            .blocking_bit_delay(Noop)
+            .build_async::<4>();
+
+        let mut future = pin!(tm.display(0, [0; 4].into_iter()));
+        let mut cx = Context::from_waker(noop_waker_ref());
+        let mut pending = 0;
+
+        let result = loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(result) => break result,
+                Poll::Pending => pending += 1,
+            }
+        };
+
+        // The command and the data transactions, unless the first one fails with the `ack` feature.
+        assert_eq!(pending, if result.is_ok() { 2 } else { 0 });
+    }
+
+    #[test]
+    fn yield_now_yields_once() {
+        let mut future = pin!(yield_now());
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(()));
+    }
+}
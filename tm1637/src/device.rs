@@ -1,11 +1,15 @@
-use ::core::marker::PhantomData;
+use ::core::{future::Future, marker::PhantomData, pin::pin};
 
 use ::embedded_hal::digital::OutputPin;
-use ::futures::Stream;
+use ::futures::{
+    future::{poll_immediate, select, Either},
+    Stream,
+};
 
 use crate::{
+    step::Step,
     tokens::{Async, Blocking},
-    Brightness, ConditionalInputPin, Error, TM1637Builder,
+    BoardProfile, Brightness, ConditionalInputPin, Error, Orientation, SegmentMap, TM1637Builder,
 };
 
 /// `TM1637` 7-segment display driver.
@@ -32,11 +36,25 @@ pub struct TM1637<const N: usize, T, CLK, DIO, DELAY> {
     ///
     /// Experiment with this value to find the best value for your display.
     delay_us: u32,
+    /// Orientation of the display.
+    orientation: Orientation,
+    /// Physical properties of the display module.
+    profile: BoardProfile,
+    /// Segment wiring of the display module.
+    segments: &'static SegmentMap,
+    /// A transaction was started and not stopped yet.
+    ///
+    /// Set if the future of a transaction is dropped before it completes, so the next transaction stops it first.
+    transaction: bool,
     _token: PhantomData<T>,
 }
 
 impl<const N: usize, T, CLK, DIO, DELAY> TM1637<N, T, CLK, DIO, DELAY> {
     /// Create a new [`TM1637`] instance.
+    ///
+    /// The display is in the [`Orientation::Normal`] orientation, see [`TM1637::set_orientation`],
+    /// uses the default profile for `N` digits, see [`BoardProfile::for_digits`] and [`TM1637::set_profile`],
+    /// and the [`SegmentMap::IDENTITY`] segment wiring, see [`TM1637::set_segments`].
     pub const fn new(
         clk: CLK,
         dio: DIO,
@@ -50,6 +68,10 @@ impl<const N: usize, T, CLK, DIO, DELAY> TM1637<N, T, CLK, DIO, DELAY> {
             delay,
             brightness,
             delay_us,
+            orientation: Orientation::Normal,
+            profile: BoardProfile::for_digits(N),
+            segments: &SegmentMap::IDENTITY,
+            transaction: false,
             _token: PhantomData,
         }
     }
@@ -76,6 +98,47 @@ impl<const N: usize, T, CLK, DIO, DELAY> TM1637<N, T, CLK, DIO, DELAY> {
         self.delay_us
     }
 
+    /// Get the orientation.
+    pub const fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Set the orientation.
+    ///
+    /// Applies to all following writes, the bytes already on the display are not rewritten.
+    /// The orientation stacks with [`DisplayOptions::flip`](crate::options::DisplayOptions::flip), see [`Orientation`].
+    ///
+    /// A write whose digits end up on consecutive addresses costs two transactions, as with [`Orientation::Normal`].
+    /// Reversing orientations keep full frames consecutive, but a partial write that is reversed onto non-consecutive grids,
+    /// e.g. on [`BoardProfile::SIX_DIGIT`], is written digit by digit and costs `n + 1` transactions for `n` digits.
+    pub const fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    /// Get the board profile.
+    pub const fn profile(&self) -> BoardProfile {
+        self.profile
+    }
+
+    /// Set the board profile.
+    ///
+    /// Applies to all following writes, the bytes already on the display are not rewritten.
+    pub const fn set_profile(&mut self, profile: BoardProfile) {
+        self.profile = profile;
+    }
+
+    /// Get the segment wiring.
+    pub const fn segments(&self) -> &'static SegmentMap {
+        self.segments
+    }
+
+    /// Set the segment wiring.
+    ///
+    /// Applies to all following writes, the bytes already on the display are not rewritten.
+    pub const fn set_segments(&mut self, segments: &'static SegmentMap) {
+        self.segments = segments;
+    }
+
     /// Get a reference to the clock pin.
     pub const fn clk(&self) -> &CLK {
         &self.clk
@@ -118,30 +181,56 @@ where
     DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
     DELAY: ::embedded_hal_async::delay::DelayNs,
 {
-    /// Scroll the given `iter` of bytes on the display starting from `position` with a delay of `delay_ms` milliseconds.
+    /// Mark the end of a transaction with a zero delay.
+    ///
+    /// [`BlockingBitDelay`](crate::delay::BlockingBitDelay) yields to the executor on this delay.
+    async fn end_transaction(&mut self) {
+        self.delay.delay_ns(0).await;
+    }
+
+    /// Execute the given `iter` of steps on the display, waiting the delay of each step in milliseconds after executing it, until the `signal` resolves.
+    ///
+    /// The `signal` is checked before each step and awaited during the delays, so it never interrupts a step.
+    /// Dropping the stream while a step is executing abandons the step, the interrupted transaction is stopped by the next one, see [`TM1637::recover`].
     ///
     /// ## Note
     ///
     /// - The stream does not stop on error.
-    pub(crate) fn scroll<'a>(
+    pub(crate) fn animate_until<'a, I>(
         &'a mut self,
-        position: usize,
-        delay_ms: u32,
-        iter: impl Iterator<Item = impl Iterator<Item = u8>> + 'a,
-    ) -> impl Stream<Item = Result<(), Error<ERR>>> + 'a {
-        futures::stream::unfold((self, iter), move |(this, mut bytes)| async move {
-            match bytes.next() {
-                Some(window) => match this.display(position, window).await {
-                    Ok(_) => {
-                        this.delay.delay_ms(delay_ms).await;
-
-                        Some((Ok(()), (this, bytes)))
-                    }
-                    Err(e) => Some((Err(e), (this, bytes))),
-                },
-                None => None,
-            }
-        })
+        iter: impl Iterator<Item = (Step<I>, u32)> + 'a,
+        signal: impl Future + Unpin + 'a,
+    ) -> impl Stream<Item = Result<(), Error<ERR>>> + 'a
+    where
+        I: Iterator<Item = u8> + 'a,
+    {
+        futures::stream::unfold(
+            (self, iter, Some(signal)),
+            move |(this, mut steps, signal)| async move {
+                let mut signal = signal?;
+
+                if poll_immediate(&mut signal).await.is_some() {
+                    return None;
+                }
+
+                let (step, delay_ms) = steps.next()?;
+
+                if let Err(e) = this.execute(step).await {
+                    return Some((Err(e), (this, steps, Some(signal))));
+                }
+
+                let signaled = {
+                    let delay = pin!(this.delay.delay_ms(delay_ms));
+
+                    matches!(select(&mut signal, delay).await, Either::Left(_))
+                };
+
+                match signaled {
+                    true => Some((Ok(()), (this, steps, None))),
+                    false => Some((Ok(()), (this, steps, Some(signal)))),
+                }
+            },
+        )
     }
 }
 
@@ -151,25 +240,35 @@ where
     DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
     DELAY: ::embedded_hal::delay::DelayNs,
 {
-    /// Scroll the given `iter` of bytes on the display starting from `position` with a delay of `delay_ms` milliseconds.
+    /// End a transaction.
+    ///
+    /// Nothing to do in blocking mode.
+    fn end_transaction(&mut self) {}
+
+    /// Execute the given `iter` of steps on the display, waiting the delay of each step in milliseconds after executing it, until the `signal` returns `true`.
+    ///
+    /// The `signal` is checked before each step.
     ///
     /// ## Note
     ///
     /// - The iterator does not stop on error.
-    pub(crate) fn scroll<'a>(
+    pub(crate) fn animate_until<'a, I>(
         &'a mut self,
-        position: usize,
-        delay_ms: u32,
-        iter: impl Iterator<Item = impl Iterator<Item = u8>> + 'a,
-    ) -> impl Iterator<Item = Result<(), Error<ERR>>> + 'a {
-        iter.map(move |bytes| match self.display(position, bytes) {
-            Ok(_) => {
-                self.delay.delay_ms(delay_ms);
-
-                Ok(())
-            }
-            Err(e) => Err(e),
-        })
+        iter: impl Iterator<Item = (Step<I>, u32)> + 'a,
+        mut signal: impl FnMut() -> bool + 'a,
+    ) -> impl Iterator<Item = Result<(), Error<ERR>>> + 'a
+    where
+        I: Iterator<Item = u8> + 'a,
+    {
+        iter.take_while(move |_| !signal())
+            .map(move |(step, delay_ms)| match self.execute(step) {
+                Ok(_) => {
+                    self.delay.delay_ms(delay_ms);
+
+                    Ok(())
+                }
+                Err(e) => Err(e),
+            })
     }
 }
 
@@ -180,7 +279,12 @@ where
 )]
 pub mod module {
     use crate::{
-        options::{circles::CirclesDisplayOptions, DisplayOptions},
+        board,
+        options::{
+            circles::CirclesDisplayOptions, BreatheOptions, DisplayOptions, FadeOptions,
+            ProgressOptions,
+        },
+        step::Step,
         tokens::NotFlipped,
         Brightness, ConditionalInputPin, Error, Identity, TM1637,
     };
@@ -248,7 +352,15 @@ pub mod module {
         }
 
         /// Start the communication with the display.
+        ///
+        /// Stops the previous transaction first if it was interrupted.
         async fn start(&mut self) -> Result<(), Error<ERR>> {
+            if self.transaction {
+                self.stop().await?;
+            }
+
+            self.transaction = true;
+
             self.dio.set_high()?;
             self.clk.set_high()?;
             self.bit_delay().await;
@@ -266,6 +378,22 @@ pub mod module {
             self.dio.set_high()?;
             self.bit_delay().await;
 
+            self.transaction = false;
+
+            self.end_transaction().await;
+
+            Ok(())
+        }
+
+        /// End a transaction that was interrupted by dropping a future of the driver.
+        ///
+        /// The next transaction does this automatically, call it to release the bus right away, e.g. before [`TM1637::into_parts`].
+        /// Does nothing if no transaction was interrupted. The bytes of the interrupted write are not rewritten.
+        pub async fn recover(&mut self) -> Result<(), Error<ERR>> {
+            if self.transaction {
+                self.stop().await?;
+            }
+
             Ok(())
         }
 
@@ -293,10 +421,10 @@ pub mod module {
         ) -> Result<(), Error<ERR>> {
             self.start().await?;
 
-            self.write_byte(0xc0 | (position as u8 & 0x03)).await?;
+            self.write_byte(0xc0 | (position as u8 & 0x07)).await?;
 
             for byte in bytes {
-                self.write_byte(byte).await?;
+                self.write_byte(self.segments.map(byte)).await?;
             }
 
             self.stop().await?;
@@ -304,6 +432,20 @@ pub mod module {
             Ok(())
         }
 
+        /// Perform command 1 and 2 in fixed address mode for a single `byte`.
+        async fn write_fixed_display_cmd(
+            &mut self,
+            address: usize,
+            byte: u8,
+        ) -> Result<(), Error<ERR>> {
+            self.start().await?;
+            self.write_byte(0xc0 | (address as u8 & 0x07)).await?;
+            self.write_byte(self.segments.map(byte)).await?;
+            self.stop().await?;
+
+            Ok(())
+        }
+
         /// Perform command 3.
         async fn write_brightness_cmd(&mut self, brightness: Brightness) -> Result<(), Error<ERR>> {
             self.write_cmd(brightness as u8).await
@@ -348,16 +490,47 @@ pub mod module {
         /// Write the given `bytes` to the display starting from `position`.
         ///
         /// Brightness level will not be written to the device on each call. Make sure to call [`TM1637::set_brightness`] or [`TM1637::init`] to set the brightness level.
+        ///
+        /// The bytes are ordered from left to right and transformed according to [`TM1637::orientation`] and [`TM1637::profile`].
+        /// Digits driven by consecutive addresses are written in a single transaction, otherwise each digit is written using its fixed address.
+        ///
+        /// 6-digit displays default to [`BoardProfile::SIX_DIGIT`](crate::BoardProfile::SIX_DIGIT), which reorders the bytes for the common 6-digit module.
+        /// Set [`BoardProfile::new`](crate::BoardProfile::new) with `6` digits to write the bytes to the addresses in order.
         pub async fn display(
             &mut self,
             position: usize,
             bytes: impl Iterator<Item = u8>,
         ) -> Result<(), Error<ERR>> {
-            // Comm 1
-            self.write_start_display_cmd().await?;
+            let digits = self.orientation.apply(board::digits::<N>(position, bytes));
+            let addresses = self.profile.addresses(digits);
+
+            let (Some(first), Some(last)) = (
+                addresses.iter().position(Option::is_some),
+                addresses.iter().rposition(Option::is_some),
+            ) else {
+                return Ok(());
+            };
 
-            // Comm 2
-            self.write_display_cmd(position, bytes).await?;
+            if addresses[first..=last].iter().all(Option::is_some) {
+                // Comm 1
+                self.write_start_display_cmd().await?;
+
+                // Comm 2
+                self.write_display_cmd(first, addresses[first..=last].iter().flatten().copied())
+                    .await?;
+
+                return Ok(());
+            }
+
+            // Comm 1 in fixed address mode
+            self.write_cmd(0x44).await?;
+
+            for (address, byte) in addresses.into_iter().enumerate() {
+                if let Some(byte) = byte {
+                    // Comm 2
+                    self.write_fixed_display_cmd(address, byte).await?;
+                }
+            }
 
             Ok(())
         }
@@ -373,6 +546,19 @@ pub mod module {
             self.display(position, bytes.iter().copied()).await
         }
 
+        /// Execute a single animation [`Step`].
+        pub(crate) async fn execute(
+            &mut self,
+            step: Step<impl Iterator<Item = u8>>,
+        ) -> Result<(), Error<ERR>> {
+            match step {
+                Step::Display(position, bytes) => self.display(position, bytes).await,
+                Step::On => self.on().await,
+                Step::Off => self.off().await,
+                Step::Brightness(brightness) => self.set_brightness(brightness).await,
+            }
+        }
+
         /// High-level API for static or animated display operations.
         ///
         /// # Example
@@ -405,5 +591,36 @@ pub mod module {
         pub const fn circles(&mut self) -> CirclesDisplayOptions<'_, N, Token, CLK, DIO, DELAY> {
             CirclesDisplayOptions::new(self)
         }
+
+        /// High-level API for progress indicators showing the given `fraction` between `0.0` and `1.0`.
+        ///
+        /// See [`ProgressOptions`].
+        pub const fn progress(
+            &mut self,
+            fraction: f32,
+        ) -> ProgressOptions<'_, N, Token, CLK, DIO, DELAY> {
+            ProgressOptions::new(self, fraction)
+        }
+
+        /// High-level API for fading from the current brightness level to `brightness` in `duration_ms` milliseconds.
+        ///
+        /// See [`FadeOptions`].
+        pub const fn fade_to(
+            &mut self,
+            brightness: Brightness,
+            duration_ms: u32,
+        ) -> FadeOptions<'_, N, Token, CLK, DIO, DELAY> {
+            FadeOptions::new_with_defaults(self, brightness, duration_ms)
+        }
+
+        /// High-level API for a breathing animation with a period of `period_ms` milliseconds.
+        ///
+        /// See [`BreatheOptions`].
+        pub const fn breathe(
+            &mut self,
+            period_ms: u32,
+        ) -> BreatheOptions<'_, N, Token, CLK, DIO, DELAY> {
+            BreatheOptions::new_with_defaults(self, period_ms)
+        }
     }
 }
@@ -4,9 +4,8 @@
 //! that can be sent to a TM1637 display.
 //!
 //! There are versions of these functions that are meant for 4-digit displays
-//! and for 6-digit displays. The 6-digit versions take into account that the
-//! order of the bytes does not directly correlate with the order of the physical
-//! digits.
+//! and for 6-digit displays. The bytes are ordered from left to right,
+//! the [`BoardProfile`](crate::BoardProfile) of the device maps them to the physical digits.
 //!
 //! All numbers are aligned to the right.
 //!
@@ -84,8 +83,7 @@ pub fn i32_to_6digits(n: i32) -> [u8; 6] {
         };
     }
 
-    // Swizzle bytes around to fit the order of 6-digit displays
-    [b[2], b[1], b[0], b[5], b[4], b[3]]
+    b
 }
 
 /// Formats a [`i8`] clamped between `-9` and `99`, appending the degrees symbol `(°)`
@@ -183,6 +181,57 @@ pub fn clock_to_4digits(hour: u8, minute: u8, colon: bool) -> [u8; 4] {
     b
 }
 
+/// Formats a date as `DD.MM`, for a `4-digit display`.
+///
+/// The day and month are clamped to `99` and padded with a leading zero.
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::{formatters::date_to_4digits, mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.display_slice(0, &date_to_4digits(18, 10)).ok();
+/// ```
+pub fn date_to_4digits(day: u8, month: u8) -> [u8; 4] {
+    let [d0, d1] = padded_2digits(day);
+    let [m0, m1] = padded_2digits(month);
+
+    [d0, d1 | 0b1000_0000, m0, m1]
+}
+
+/// Formats a date as `DD.MM.YY`, for a `6-digit display`.
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::{formatters::date_to_6digits, mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<6>();
+///
+/// tm.display_slice(0, &date_to_6digits(18, 10, 2026)).ok();
+/// ```
+///
+/// Only the last two digits of the year are displayed.
+pub fn date_to_6digits(day: u8, month: u8, year: u16) -> [u8; 6] {
+    let [d0, d1] = padded_2digits(day);
+    let [m0, m1] = padded_2digits(month);
+    let [y0, y1] = padded_2digits((year % 100) as u8);
+
+    [d0, d1 | 0b1000_0000, m0, m1 | 0b1000_0000, y0, y1]
+}
+
+/// Formats a [`u8`] clamped to `99` with a leading zero.
+pub(crate) fn padded_2digits(n: u8) -> [u8; 2] {
+    let n = n.min(99);
+
+    [
+        DigitBits::from_digit(n / 10) as u8,
+        DigitBits::from_digit(n % 10) as u8,
+    ]
+}
+
 /// Formats a [`i16`] clamped between `-999` and `9999`, for an `upside-down 4-digit display`.
 pub fn i16_to_upside_down_4digits(n: i16) -> [u8; 4] {
     let mut bytes: [u8; 4] = [0; 4];
@@ -239,6 +288,5 @@ pub fn f32_to_6digits(n: f32, decimals: u8) -> [u8; 6] {
         };
     }
 
-    // Swizzle bytes around to fit the order of 6-digit displays
-    [b[2], b[1], b[0], b[5], b[4], b[3]]
+    b
 }
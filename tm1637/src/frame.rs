@@ -0,0 +1,73 @@
+/// Maximum number of bytes buffered by animations that need to replay their bytes.
+pub(crate) const BUFFER_CAPACITY: usize = 64;
+
+/// A buffered sequence of at most `N` bytes.
+///
+/// Used by animations that need to display the same bytes more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct Frame<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Frame<N> {
+    /// Create a new [`Frame`] from the first `N` bytes of the given iterator.
+    pub(crate) fn new(iter: impl Iterator<Item = u8>) -> Self {
+        let mut bytes = [0; N];
+        let mut len = 0;
+
+        for (slot, byte) in bytes.iter_mut().zip(iter) {
+            *slot = byte;
+            len += 1;
+        }
+
+        Self { bytes, len }
+    }
+
+    /// Returns the number of bytes in the frame.
+    pub(crate) const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the byte at `index` or `0` if the index is out of bounds.
+    pub(crate) const fn get(&self, index: usize) -> u8 {
+        if index < self.len {
+            self.bytes[index]
+        } else {
+            0
+        }
+    }
+
+    /// Resize the frame to `len` bytes, padding with zeros.
+    ///
+    /// `len` is clamped to `N`.
+    pub(crate) fn resize(mut self, len: usize) -> Self {
+        let len = len.min(N);
+
+        for byte in self.bytes.iter_mut().skip(len) {
+            *byte = 0;
+        }
+
+        self.len = len;
+        self
+    }
+
+    /// Map each byte and its index using the provided function.
+    pub(crate) fn map(mut self, mut f: impl FnMut(usize, u8) -> u8) -> Self {
+        for (i, byte) in self.bytes.iter_mut().take(self.len).enumerate() {
+            *byte = f(i, *byte);
+        }
+
+        self
+    }
+}
+
+impl<const N: usize> IntoIterator for Frame<N> {
+    type Item = u8;
+    type IntoIter = ::core::iter::Take<::core::array::IntoIter<u8, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bytes.into_iter().take(self.len)
+    }
+}
@@ -0,0 +1,325 @@
+//! Content layers with priorities.
+
+/// Content of `L` layers for a display with `N` positions.
+///
+/// Each layer holds the bytes of a full display, from left to right, or nothing.
+/// The layer with the highest index that holds content is shown.
+/// Removing it reveals the content of the layer below.
+///
+/// Content can be set until a point in time in milliseconds, see [`Layers::set_until`] and [`Layers::expire`].
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::layers::Layers;
+///
+/// const CLOCK: usize = 0;
+/// const ALERT: usize = 1;
+///
+/// let mut layers = Layers::<4, 2>::new();
+///
+/// layers.set(CLOCK, [1, 2, 3, 4]);
+/// layers.set(ALERT, [5, 6, 7, 8]);
+/// assert_eq!(layers.top(), Some((ALERT, [5, 6, 7, 8])));
+///
+/// layers.clear(ALERT);
+/// assert_eq!(layers.top(), Some((CLOCK, [1, 2, 3, 4])));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Layers<const N: usize, const L: usize> {
+    frames: [Option<Content<N>>; L],
+}
+
+/// Content of a single layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Content<const N: usize> {
+    bytes: [u8; N],
+    /// Point in time in milliseconds at which the content is removed.
+    until_ms: Option<u32>,
+}
+
+impl<const N: usize, const L: usize> Layers<N, L> {
+    /// Create a new [`Layers`] instance without content.
+    pub const fn new() -> Self {
+        Self { frames: [None; L] }
+    }
+
+    /// Set the content of the `layer` until it is cleared.
+    ///
+    /// Layers out of bounds are ignored.
+    pub fn set(&mut self, layer: usize, bytes: [u8; N]) {
+        self.insert(layer, bytes, None);
+    }
+
+    /// Set the content of the `layer` until the point in time `until_ms`.
+    ///
+    /// See [`Layers::expire`].
+    pub fn set_until(&mut self, layer: usize, bytes: [u8; N], until_ms: u32) {
+        self.insert(layer, bytes, Some(until_ms));
+    }
+
+    fn insert(&mut self, layer: usize, bytes: [u8; N], until_ms: Option<u32>) {
+        if let Some(frame) = self.frames.get_mut(layer) {
+            *frame = Some(Content { bytes, until_ms });
+        }
+    }
+
+    /// Remove the content of the `layer`.
+    pub fn clear(&mut self, layer: usize) {
+        if let Some(frame) = self.frames.get_mut(layer) {
+            *frame = None;
+        }
+    }
+
+    /// Get the content of the `layer`.
+    pub fn get(&self, layer: usize) -> Option<[u8; N]> {
+        self.frames
+            .get(layer)
+            .copied()
+            .flatten()
+            .map(|content| content.bytes)
+    }
+
+    /// Get the highest layer holding content and its content.
+    pub fn top(&self) -> Option<(usize, [u8; N])> {
+        self.frames
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(layer, frame)| frame.map(|content| (layer, content.bytes)))
+    }
+
+    /// Returns `true` if the `layer` is shown.
+    pub fn is_top(&self, layer: usize) -> bool {
+        matches!(self.top(), Some((top, _)) if top == layer)
+    }
+
+    /// Remove the content that expired at the point in time `now_ms`.
+    ///
+    /// Points in time wrap around after [`u32::MAX`] milliseconds.
+    pub fn expire(&mut self, now_ms: u32) {
+        for frame in self.frames.iter_mut() {
+            if let Some(Content {
+                until_ms: Some(until_ms),
+                ..
+            }) = frame
+            {
+                if reached(now_ms, *until_ms) {
+                    *frame = None;
+                }
+            }
+        }
+    }
+
+    /// Get the milliseconds from `now_ms` until the next content expires.
+    pub fn deadline(&self, now_ms: u32) -> Option<u32> {
+        self.frames
+            .iter()
+            .flatten()
+            .filter_map(|content| content.until_ms)
+            .map(|until_ms| match reached(now_ms, until_ms) {
+                true => 0,
+                false => until_ms.wrapping_sub(now_ms),
+            })
+            .min()
+    }
+}
+
+impl<const N: usize, const L: usize> Default for Layers<N, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `true` if the point in time `now_ms` is at or after `until_ms`.
+pub(crate) const fn reached(now_ms: u32, until_ms: u32) -> bool {
+    now_ms.wrapping_sub(until_ms) <= u32::MAX / 2
+}
+
+/// Composites [`Layers`] into the bytes to write to the display.
+///
+/// Keeps track of the bytes shown on the display and returns new bytes only when the shown content changes,
+/// e.g. after setting the shown layer or when an overlay expires and the layer below is restored.
+///
+/// Works in `blocking` mode, by calling [`Compositor::poll`] from the main loop, and in `async` mode,
+/// by waiting for [`Compositor::deadline`] or for new content. See [`SharedDisplay`](crate::shared::SharedDisplay) for sharing a display between tasks.
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::{
+///     layers::Compositor,
+///     mappings::{DigitBits, UpCharBits},
+///     mock::Noop,
+///     TM1637Builder,
+/// };
+///
+/// const CLOCK: usize = 0;
+/// const ALERT: usize = 1;
+/// const NOTIFICATION: usize = 2;
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+/// let mut compositor = Compositor::<4, 3>::new();
+///
+/// let now_ms = 0;
+///
+/// compositor.set(CLOCK, [DigitBits::One as u8, DigitBits::Two as u8, DigitBits::Three as u8, DigitBits::Four as u8]);
+/// // Show "Err5" until dismissed.
+/// compositor.set(ALERT, [UpCharBits::UpE as u8, 0b01010000, 0b01010000, DigitBits::Five as u8]);
+/// // Show a notification for 2 seconds.
+/// compositor.set_for(NOTIFICATION, [0b01000000; 4], now_ms, 2000);
+///
+/// // In the main loop
+/// if let Some(bytes) = compositor.poll(now_ms) {
+///     tm.options().slice(&bytes).display().ok();
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Compositor<const N: usize, const L: usize> {
+    layers: Layers<N, L>,
+    /// The bytes on the display or [`None`] if unknown.
+    shown: Option<[u8; N]>,
+}
+
+impl<const N: usize, const L: usize> Compositor<N, L> {
+    /// Create a new [`Compositor`] without content.
+    pub const fn new() -> Self {
+        Self {
+            layers: Layers::new(),
+            shown: None,
+        }
+    }
+
+    /// Get a reference to the layers.
+    pub const fn layers(&self) -> &Layers<N, L> {
+        &self.layers
+    }
+
+    /// Set the content of the `layer` until it is cleared.
+    pub fn set(&mut self, layer: usize, bytes: [u8; N]) {
+        self.layers.set(layer, bytes);
+    }
+
+    /// Set the content of the `layer` for `duration_ms` milliseconds from `now_ms`.
+    pub fn set_for(&mut self, layer: usize, bytes: [u8; N], now_ms: u32, duration_ms: u32) {
+        self.layers
+            .set_until(layer, bytes, now_ms.wrapping_add(duration_ms));
+    }
+
+    /// Remove the content of the `layer`.
+    pub fn clear(&mut self, layer: usize) {
+        self.layers.clear(layer);
+    }
+
+    /// Forget the bytes on the display, so the next call to [`Compositor::poll`] or [`Compositor::draw`] returns them again.
+    ///
+    /// Call this if writing the returned bytes failed or the display was written to directly.
+    pub fn invalidate(&mut self) {
+        self.shown = None;
+    }
+
+    /// Get the milliseconds from `now_ms` until the next content expires.
+    pub fn deadline(&self, now_ms: u32) -> Option<u32> {
+        self.layers.deadline(now_ms)
+    }
+
+    /// Remove the content that expired at `now_ms` and return the bytes to write if the shown content changed.
+    pub fn poll(&mut self, now_ms: u32) -> Option<[u8; N]> {
+        self.layers.expire(now_ms);
+
+        self.draw()
+    }
+
+    /// Return the bytes to write if the shown content changed.
+    ///
+    /// The display is cleared if no layer holds content.
+    pub fn draw(&mut self) -> Option<[u8; N]> {
+        let bytes = match self.layers.top() {
+            Some((_, bytes)) => bytes,
+            None => [0; N],
+        };
+
+        if self.shown == Some(bytes) {
+            return None;
+        }
+
+        self.shown = Some(bytes);
+
+        Some(bytes)
+    }
+}
+
+impl<const N: usize, const L: usize> Default for Compositor<N, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lower_layers_are_revealed() {
+        let mut layers = Layers::<4, 3>::new();
+
+        assert_eq!(layers.top(), None);
+
+        layers.set(0, [1; 4]);
+        layers.set(2, [3; 4]);
+        layers.set(1, [2; 4]);
+
+        assert!(layers.is_top(2));
+
+        layers.clear(2);
+        assert_eq!(layers.top(), Some((1, [2; 4])));
+
+        layers.clear(1);
+        layers.clear(0);
+        assert_eq!(layers.top(), None);
+
+        // Out of bounds layers are ignored.
+        layers.set(3, [4; 4]);
+        assert_eq!(layers.top(), None);
+    }
+
+    #[test]
+    fn expired_overlay_restores_the_base() {
+        let mut compositor = Compositor::<4, 3>::new();
+
+        compositor.set(0, [1; 4]);
+        compositor.set_for(2, [3; 4], 1000, 2000);
+
+        assert_eq!(compositor.poll(1000), Some([3; 4]));
+        assert_eq!(compositor.poll(2999), None);
+        assert_eq!(compositor.deadline(2999), Some(1));
+
+        // Changing a hidden layer writes nothing.
+        compositor.set(0, [2; 4]);
+        assert_eq!(compositor.poll(2999), None);
+
+        assert_eq!(compositor.poll(3000), Some([2; 4]));
+        assert_eq!(compositor.deadline(3000), None);
+
+        compositor.clear(0);
+        assert_eq!(compositor.poll(3001), Some([0; 4]));
+    }
+
+    #[test]
+    fn deadlines_wrap_around() {
+        let mut layers = Layers::<4, 1>::new();
+
+        layers.set_until(0, [1; 4], 10);
+
+        assert_eq!(layers.deadline(u32::MAX - 9), Some(20));
+
+        layers.expire(u32::MAX);
+        assert_eq!(layers.top(), Some((0, [1; 4])));
+
+        layers.expire(10);
+        assert_eq!(layers.top(), None);
+    }
+}
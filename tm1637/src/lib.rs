@@ -36,14 +36,20 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod align;
+pub mod animator;
+mod board;
 mod brightness;
 mod builder;
+pub mod canvas;
 mod conditional;
+pub mod delay;
 mod device;
 mod error;
 mod exact_size;
 pub mod formatters;
+mod frame;
 mod identity;
+pub mod layers;
 pub mod mappings;
 mod maybe_flipped;
 #[doc(hidden)]
@@ -51,12 +57,21 @@ pub mod mock;
 mod mode;
 pub mod numbers;
 pub mod options;
+mod orientation;
+mod runner;
+mod segments;
+pub mod shared;
+mod step;
 pub mod str;
 pub mod tokens;
 
+pub use board::{BoardProfile, DotKind};
 pub use brightness::Brightness;
 pub use builder::TM1637Builder;
 pub(crate) use conditional::ConditionalInputPin;
 pub use device::TM1637;
 pub use error::Error;
 pub(crate) use identity::Identity;
+pub use orientation::Orientation;
+pub use segments::SegmentMap;
+pub use step::Step;
@@ -574,6 +574,31 @@ pub const fn flip_mirror(byte: u8) -> u8 {
     mirror(flip(byte))
 }
 
+/// Shifts the segments of a byte down by half a digit.
+///
+/// Moves the segments:
+/// - A to G
+/// - G to D
+/// - F to E
+/// - B to C
+///
+/// Segments D, E and C are shifted out. The dot is kept.
+pub const fn shift_down(byte: u8) -> u8 {
+    let a_to_g = (byte & 0b00000001) << 6;
+    let g_to_d = (byte & 0b01000000) >> 3;
+    let f_to_e = (byte & 0b00100000) >> 1;
+    let b_to_c = (byte & 0b00000010) << 1;
+
+    (byte & 0b10000000) | a_to_g | g_to_d | f_to_e | b_to_c
+}
+
+/// Shifts the segments of a byte up by half a digit.
+///
+/// The inverse direction of [`shift_down`], calculated by shifting the [`flip`]ped byte down.
+pub const fn shift_up(byte: u8) -> u8 {
+    flip(shift_down(flip(byte)))
+}
+
 /// Converts an `ASCII` byte to a 7-segment display byte.
 ///
 /// Unknown characters are converted to `0` (all segments off).
@@ -857,6 +882,24 @@ mod tests {
         assert_eq!(flipped_e, should_flipped_e);
     }
 
+    #[test]
+    fn shifted_eight() {
+        let eight = DigitBits::Eight as u8;
+        let should_shifted_down = SegmentBits::SegG as u8
+            | SegmentBits::SegD as u8
+            | SegmentBits::SegE as u8
+            | SegmentBits::SegC as u8;
+        let should_shifted_up = SegmentBits::SegG as u8
+            | SegmentBits::SegA as u8
+            | SegmentBits::SegF as u8
+            | SegmentBits::SegB as u8;
+
+        assert_eq!(shift_down(eight), should_shifted_down);
+        assert_eq!(shift_up(eight), should_shifted_up);
+        assert_eq!(shift_down(shift_down(eight)), SegmentBits::SegD as u8);
+        assert_eq!(shift_up(shift_up(eight)), SegmentBits::SegA as u8);
+    }
+
     #[test]
     fn mirrored_four() {
         let four = DigitBits::Four as u8;
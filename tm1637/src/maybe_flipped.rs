@@ -1,4 +1,8 @@
-use crate::tokens::{Flipped, NotFlipped};
+use crate::{
+    frame::Frame,
+    mappings::{flip_mirror, SegmentBits},
+    tokens::{Flipped, NotFlipped},
+};
 
 /// A trait for recalculating the `position` and `bytes` on a `maybe flipped` display.
 pub trait MaybeFlipped<const N: usize> {
@@ -46,50 +50,19 @@ impl<const N: usize> MaybeFlipped<N> for Flipped {
         usize,
         impl DoubleEndedIterator<Item = u8> + ExactSizeIterator<Item = u8>,
     ) {
-        fn calculate_bytes<const N: usize>(
-            bytes: impl DoubleEndedIterator<Item = u8> + ExactSizeIterator<Item = u8>,
-            position: usize,
-        ) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator<Item = u8> {
-            #[auto_enums::enum_derive(DoubleEndedIterator)]
-            enum I<A, B, C> {
-                A(A),
-                B(B),
-                C(C),
-            }
-
-            impl<
-                    A: ExactSizeIterator<Item = u8>,
-                    B: ExactSizeIterator<Item = u8>,
-                    C: ExactSizeIterator<Item = u8>,
-                > ExactSizeIterator for I<A, B, C>
-            {
-                fn len(&self) -> usize {
-                    match self {
-                        I::A(a) => a.len(),
-                        I::B(b) => b.len(),
-                        I::C(c) => c.len(),
-                    }
-                }
-            }
-
-            if position > N {
-                return I::C(::core::iter::empty());
-            }
-
-            match bytes.len() + position > N {
-                true => I::A(bytes.take(N - position).rev()),
-                false => I::B(bytes.rev()),
-            }
-        }
+        const DOT: u8 = SegmentBits::Dot as u8;
 
-        let new_position = match bytes.len() + position > N {
-            true => 0,
-            false => N - bytes.len() - position,
-        };
+        let new_position = <Self as MaybeFlipped<N>>::position(position, bytes.len());
+        let frame = Frame::<N>::new(bytes.take(N.saturating_sub(position)));
 
-        let bytes = calculate_bytes::<N>(bytes, position);
+        // The physical dot of a rotated digit appears before it, so each dot is moved to the following digit,
+        // the same way as for `Orientation::Rotated`.
+        let bytes = frame.map(|index, byte| match index {
+            0 => byte & !DOT,
+            _ => (byte & !DOT) | (frame.get(index - 1) & DOT),
+        });
 
-        (new_position, bytes.map(crate::mappings::flip_mirror))
+        (new_position, bytes.into_iter().rev().map(flip_mirror))
     }
 
     fn position(position: usize, len: usize) -> usize {
@@ -8,13 +8,29 @@ use crate::{
 pub mod circles;
 mod windows;
 
+mod bar;
+mod blink;
 mod clock;
+mod date;
+mod fade;
+mod measurement;
+mod progress;
 mod repeat;
 mod scroll;
+mod timer;
+mod transition;
 
+pub use bar::*;
+pub use blink::*;
 pub use clock::*;
+pub use date::*;
+pub use fade::*;
+pub use measurement::*;
+pub use progress::*;
 pub use repeat::*;
 pub use scroll::*;
+pub use timer::*;
+pub use transition::*;
 
 /// High-level API for display operations.
 #[derive(Debug)]
@@ -88,6 +104,8 @@ impl<'d, 'b, const N: usize, T, CLK, DIO, DELAY, I, M>
     }
 
     /// Add a string.
+    ///
+    /// Decimal points that would light the colon of the [`TM1637::profile`] are dropped, see [`StrParser::with_profile`].
     pub fn str(
         self,
         str: &'b str,
@@ -104,10 +122,13 @@ impl<'d, 'b, const N: usize, T, CLK, DIO, DELAY, I, M>
     where
         I: DoubleEndedIterator<Item = u8> + ExactSizeIterator + 'b,
     {
+        let parser = StrParser::new(str)
+            .with_profile(self.device.profile(), self.position + self.iter.len());
+
         DisplayOptions {
             device: self.device,
             position: self.position,
-            iter: self.iter.exact_size_chain(StrParser::new(str)),
+            iter: self.iter.exact_size_chain(parser),
             _flip: self._flip,
         }
     }
@@ -174,6 +195,29 @@ impl<'d, 'b, const N: usize, T, CLK, DIO, DELAY, I, M>
         ClockDisplayOptions::new(self)
     }
 
+    /// Use date options.
+    pub const fn date(self) -> DateDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M> {
+        DateDisplayOptions::new(self)
+    }
+
+    /// Use measurement options to display the `value` with its `unit`.
+    pub const fn measurement(
+        self,
+        value: f32,
+        unit: Unit,
+    ) -> MeasurementDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M> {
+        MeasurementDisplayOptions::new_with_defaults(self, value, unit)
+    }
+
+    /// Use bar graph options to display `value` out of `max`.
+    pub const fn bar(
+        self,
+        value: u32,
+        max: u32,
+    ) -> BarDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M> {
+        BarDisplayOptions::new_with_defaults(self, value, max)
+    }
+
     /// Use scroll animation options.
     pub const fn scroll(self) -> ScrollDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M> {
         ScrollDisplayOptions::new_with_defaults(self)
@@ -188,6 +232,35 @@ impl<'d, 'b, const N: usize, T, CLK, DIO, DELAY, I, M>
         RepeatDisplayOptions::new_with_defaults(self)
     }
 
+    /// Run a [`Timer`] using the device `DELAY`.
+    ///
+    /// See [`TimerDisplayOptions`].
+    pub const fn timer<R: Timer>(
+        self,
+        timer: R,
+    ) -> TimerDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M, R> {
+        TimerDisplayOptions::new_with_defaults(self, timer)
+    }
+
+    /// Use blink animation options.
+    ///
+    /// See [`BlinkDisplayOptions`].
+    pub const fn blink(self) -> BlinkDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M> {
+        BlinkDisplayOptions::new_with_defaults(self)
+    }
+
+    /// Use transition animation options.
+    ///
+    /// Transition from the given `from` bytes to the bytes of this [`DisplayOptions`].
+    ///
+    /// See [`TransitionDisplayOptions`].
+    pub fn transition_from(
+        self,
+        from: impl IntoIterator<Item = u8>,
+    ) -> TransitionDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M> {
+        TransitionDisplayOptions::new_with_defaults(self, from)
+    }
+
     /// Add a dynamic dot to the display at the specified position.
     ///
     /// ## Dynamic
@@ -388,6 +461,12 @@ impl<'d, 'b, const N: usize, T, CLK, DIO, DELAY, I, M>
     }
 
     /// Flip the display.
+    ///
+    /// Rotates the bytes of this write by 180°, on top of the [`TM1637::orientation`] of the device.
+    /// Dots are moved to the following digit like for [`Orientation::Rotated`](crate::Orientation::Rotated),
+    /// so the colon of a [`BoardProfile::CLOCK`](crate::BoardProfile::CLOCK) stays on the colon digit.
+    /// The two stack: a flipped write on a device in the [`Orientation::Rotated`](crate::Orientation::Rotated) orientation is shown upright.
+    /// Prefer [`TM1637::set_orientation`] for displays mounted upside down and use this only for single writes.
     pub fn flip(
         self,
     ) -> DisplayOptions<
@@ -518,7 +597,11 @@ mod tests {
     use std::vec;
     use std::vec::Vec;
 
-    use crate::{mappings::str_from_byte, mock::Noop, TM1637Builder};
+    use crate::{
+        mappings::{str_from_byte, SegmentBits},
+        mock::Noop,
+        BoardProfile, TM1637Builder,
+    };
 
     #[test]
     fn dot_is_dynamically_tied_to_byte() {
@@ -529,9 +612,37 @@ mod tests {
 
         assert_eq!(vec!["H", "E.", "L", "L."], collected);
 
+        // The dots are moved to the following digit, so they still appear after their byte upside down.
         let (_, iter) = tm.options().str("HELLO").dot(1).dot(3).flip().calculate();
         let collected = iter.map(str_from_byte).collect::<Vec<_>>();
 
-        assert_eq!(vec!["7.", "7", "3.", "H"], collected);
+        assert_eq!(vec!["7", "7.", "3", "H"], collected);
+    }
+
+    #[test]
+    fn dots_are_routed_by_the_profile() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop)
+            .profile(BoardProfile::CLOCK)
+            .build_blocking::<4>();
+
+        // The decimal point would light the colon.
+        let (_, iter) = tm.options().str("23.5").calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(vec!["2", "3", "5"], collected);
+
+        // The colon is kept on the colon digit.
+        let (position, iter) = tm.options().str("12:34").flip().calculate();
+        let collected = iter.collect::<Vec<_>>();
+
+        assert_eq!(position, 0);
+        assert_eq!(
+            collected[1] & SegmentBits::Dot as u8,
+            SegmentBits::Dot as u8
+        );
+        assert_eq!(
+            BoardProfile::CLOCK.colon().map(|colon| collected[colon]),
+            Some(collected[1])
+        );
     }
 }
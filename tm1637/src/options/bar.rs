@@ -0,0 +1,248 @@
+//! Bar graph settings.
+
+use crate::{frame::Frame, mappings::SegmentBits};
+
+mod direction;
+mod peak;
+mod style;
+
+use super::DisplayOptions;
+pub use direction::BarDirection;
+pub use peak::PeakHold;
+pub use style::BarStyle;
+
+/// High-level API for bar graphs and level meters.
+///
+/// The bar is filled proportionally to `value / max` across all digits.
+///
+/// # Example
+///
+/// Display a level of `5` out of `8` with a peak marker at `7`.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.options()
+///     .bar(5, 8)
+///     .peak(7)
+///     .finish()
+///     .display()
+///     .ok();
+/// ```
+///
+/// Each digit has 2 of the 8 steps. The first two digits are full, the third shows the fifth step
+/// and the fourth shows the peak marker at the seventh step, both on the left segments `E/F`:
+///
+/// ```text
+/// +-----+ +-----+ +-----+ +-----+
+/// | | | | | | | | | |   | | |   |
+/// | | | | | | | | | |   | | |   |
+/// +-----+ +-----+ +-----+ +-----+
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BarDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, M> {
+    options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+    value: u32,
+    max: u32,
+    peak: Option<u32>,
+    style: BarStyle,
+    direction: BarDirection,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
+    BarDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>
+{
+    /// Create a new [`BarDisplayOptions`] instance with default settings.
+    ///
+    /// Uses [`BarStyle::Horizontal`] filling [`BarDirection::LeftToRight`] without a peak marker.
+    pub const fn new_with_defaults(
+        options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+        value: u32,
+        max: u32,
+    ) -> Self {
+        Self {
+            options,
+            value,
+            max,
+            peak: None,
+            style: BarStyle::Horizontal,
+            direction: BarDirection::LeftToRight,
+        }
+    }
+
+    /// Set the style.
+    pub const fn style(mut self, style: BarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Use the [`BarStyle::Horizontal`] style.
+    pub const fn horizontal(self) -> Self {
+        self.style(BarStyle::Horizontal)
+    }
+
+    /// Use the [`BarStyle::Vertical`] style.
+    pub const fn vertical(self) -> Self {
+        self.style(BarStyle::Vertical)
+    }
+
+    /// Set the direction.
+    pub const fn direction(mut self, direction: BarDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Mark the segments of the `peak` value.
+    ///
+    /// See [`PeakHold`] to track the peak.
+    pub const fn peak(mut self, peak: u32) -> Self {
+        self.peak = Some(peak);
+        self
+    }
+
+    /// Finish setting the bar graph.
+    pub fn finish(
+        self,
+    ) -> DisplayOptions<
+        'd,
+        N,
+        T,
+        CLK,
+        DIO,
+        DELAY,
+        impl DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+        M,
+    >
+    where
+        I: DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+    {
+        let frame = bar::<N>(self.value, self.max, self.peak, self.style, self.direction);
+
+        self.options.iter(frame.into_iter())
+    }
+}
+
+/// Segments lit by each fill step of a digit.
+const fn units(style: BarStyle, direction: BarDirection) -> &'static [u8] {
+    const LEFT: u8 = SegmentBits::SegE as u8 | SegmentBits::SegF as u8;
+    const RIGHT: u8 = SegmentBits::SegB as u8 | SegmentBits::SegC as u8;
+
+    match (style, direction) {
+        (BarStyle::Horizontal, BarDirection::LeftToRight) => &[LEFT, RIGHT],
+        (BarStyle::Horizontal, BarDirection::RightToLeft) => &[RIGHT, LEFT],
+        (BarStyle::Vertical, _) => &[
+            SegmentBits::SegD as u8,
+            SegmentBits::SegG as u8,
+            SegmentBits::SegA as u8,
+        ],
+    }
+}
+
+/// Returns the number of fill steps for `value` out of `max` with `levels` steps.
+fn level(value: u32, max: u32, levels: usize) -> usize {
+    match max {
+        0 => 0,
+        _ => (value.min(max) as u64 * levels as u64 / max as u64) as usize,
+    }
+}
+
+/// Renders the bar on `N` digits.
+fn bar<const N: usize>(
+    value: u32,
+    max: u32,
+    peak: Option<u32>,
+    style: BarStyle,
+    direction: BarDirection,
+) -> Frame<N> {
+    let units = units(style, direction);
+    let levels = N * units.len();
+
+    let filled = level(value, max, levels);
+    let peak = peak.map(|peak| level(peak, max, levels)).unwrap_or(0);
+
+    Frame::new((0..N).map(|digit| {
+        let digit = match direction {
+            BarDirection::LeftToRight => digit,
+            BarDirection::RightToLeft => N - 1 - digit,
+        };
+
+        units
+            .iter()
+            .enumerate()
+            .map(|(i, unit)| (digit * units.len() + i, unit))
+            .filter(|(step, _)| *step < filled || *step + 1 == peak)
+            .fold(0, |byte, (_, unit)| byte | unit)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: u8 = SegmentBits::SegE as u8 | SegmentBits::SegF as u8;
+    const RIGHT: u8 = SegmentBits::SegB as u8 | SegmentBits::SegC as u8;
+
+    fn bytes<const N: usize>(frame: Frame<N>) -> [u8; N] {
+        let mut bytes = [0; N];
+
+        bytes
+            .iter_mut()
+            .zip(frame)
+            .for_each(|(slot, byte)| *slot = byte);
+
+        bytes
+    }
+
+    #[test]
+    fn horizontal_fills_full_then_partial_digits() {
+        let frame = bar::<4>(
+            5,
+            8,
+            Some(7),
+            BarStyle::Horizontal,
+            BarDirection::LeftToRight,
+        );
+
+        assert_eq!(bytes(frame), [LEFT | RIGHT, LEFT | RIGHT, LEFT, LEFT]);
+
+        let frame = bar::<4>(3, 8, None, BarStyle::Horizontal, BarDirection::RightToLeft);
+
+        assert_eq!(bytes(frame), [0, 0, RIGHT, LEFT | RIGHT]);
+    }
+
+    #[test]
+    fn vertical_fills_three_levels_per_digit() {
+        let frame = bar::<4>(4, 12, None, BarStyle::Vertical, BarDirection::LeftToRight);
+
+        let full = SegmentBits::SegD as u8 | SegmentBits::SegG as u8 | SegmentBits::SegA as u8;
+
+        assert_eq!(bytes(frame), [full, SegmentBits::SegD as u8, 0, 0]);
+    }
+
+    #[test]
+    fn peak_hold_falls_back_after_hold() {
+        let mut peak = PeakHold::new(2);
+
+        assert_eq!(peak.update(8), 8);
+        assert_eq!(peak.update(3), 8);
+        assert_eq!(peak.update(2), 8);
+        assert_eq!(peak.update(1), 1);
+    }
+
+    #[test]
+    fn zero_max_renders_an_empty_bar() {
+        assert_eq!(
+            bytes(bar::<4>(
+                0,
+                0,
+                Some(1),
+                BarStyle::Horizontal,
+                BarDirection::LeftToRight
+            )),
+            [0; 4]
+        );
+    }
+}
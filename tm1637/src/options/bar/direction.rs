@@ -0,0 +1,10 @@
+/// Direction in which a bar graph fills the digits.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BarDirection {
+    /// Fill from the first digit to the last digit.
+    #[default]
+    LeftToRight,
+    /// Fill from the last digit to the first digit.
+    RightToLeft,
+}
@@ -0,0 +1,57 @@
+/// Peak-hold tracker for level meters.
+///
+/// Holds the highest value for a number of updates before falling back to the current value.
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, options::PeakHold, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// let mut peak = PeakHold::new(10);
+///
+/// for level in [3, 8, 5, 2] {
+///     tm.options()
+///         .bar(level, 8)
+///         .peak(peak.update(level))
+///         .finish()
+///         .display()
+///         .ok();
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeakHold {
+    peak: u32,
+    hold: u32,
+    remaining: u32,
+}
+
+impl PeakHold {
+    /// Create a new [`PeakHold`] holding the peak for `hold` updates.
+    pub const fn new(hold: u32) -> Self {
+        Self {
+            peak: 0,
+            hold,
+            remaining: 0,
+        }
+    }
+
+    /// Returns the held peak.
+    pub const fn peak(&self) -> u32 {
+        self.peak
+    }
+
+    /// Update the tracker with the current `value` and return the held peak.
+    pub const fn update(&mut self, value: u32) -> u32 {
+        if value >= self.peak || self.remaining == 0 {
+            self.peak = value;
+            self.remaining = self.hold;
+        } else {
+            self.remaining -= 1;
+        }
+
+        self.peak
+    }
+}
@@ -0,0 +1,10 @@
+/// Style of a bar graph.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BarStyle {
+    /// Each digit is filled in two steps: the left vertical segments `E/F`, then the right vertical segments `B/C`.
+    #[default]
+    Horizontal,
+    /// Each digit is filled in three steps from the bottom: `D`, `G`, then `A`.
+    Vertical,
+}
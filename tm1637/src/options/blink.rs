@@ -0,0 +1,371 @@
+//! Blink animation settings.
+
+use crate::{
+    frame::Frame,
+    mappings::SegmentBits,
+    step::{cycles, Step},
+    TM1637,
+};
+
+mod target;
+
+use super::DisplayOptions;
+pub use target::BlinkTarget;
+
+/// High-level API for blink animations.
+///
+/// # Example
+///
+/// Blink the colon of a clock 5 times.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.options()
+///     .str("12.34")
+///     .blink()
+///     .dots()
+///     .on_ms(500)
+///     .off_ms(500)
+///     .times(5)
+///     .finish()
+///     .run();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BlinkDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, M> {
+    options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+    on_ms: u32,
+    off_ms: u32,
+    count: Option<usize>,
+    target: BlinkTarget,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
+    BlinkDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>
+{
+    /// Create a new [`BlinkDisplayOptions`] instance.
+    ///
+    /// A `count` of [`None`] blinks forever.
+    pub const fn new(
+        options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+        on_ms: u32,
+        off_ms: u32,
+        count: Option<usize>,
+        target: BlinkTarget,
+    ) -> Self {
+        Self {
+            options,
+            on_ms,
+            off_ms,
+            count,
+            target,
+        }
+    }
+
+    /// Create a new [`BlinkDisplayOptions`] instance with default settings.
+    ///
+    /// Blinks the whole display 3 times with 500ms on and 500ms off.
+    pub const fn new_with_defaults(
+        options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+    ) -> Self {
+        Self::new(options, 500, 500, Some(3), BlinkTarget::Display)
+    }
+
+    /// Set the duration in milliseconds of the on phase.
+    pub const fn on_ms(mut self, on_ms: u32) -> Self {
+        self.on_ms = on_ms;
+        self
+    }
+
+    /// Set the duration in milliseconds of the off phase.
+    pub const fn off_ms(mut self, off_ms: u32) -> Self {
+        self.off_ms = off_ms;
+        self
+    }
+
+    /// Set the duration in milliseconds of both the on and off phases.
+    pub const fn delay_ms(mut self, delay_ms: u32) -> Self {
+        self.on_ms = delay_ms;
+        self.off_ms = delay_ms;
+        self
+    }
+
+    /// Blink `count` times.
+    pub const fn times(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Blink forever.
+    pub const fn forever(mut self) -> Self {
+        self.count = None;
+        self
+    }
+
+    /// Set the blink target.
+    pub const fn target(mut self, target: BlinkTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Set the blink target to [`BlinkTarget::Display`].
+    pub const fn display(mut self) -> Self {
+        self.target = BlinkTarget::Display;
+        self
+    }
+
+    /// Set the blink target to [`BlinkTarget::Positions`] using the given `mask`.
+    pub const fn positions(mut self, mask: u8) -> Self {
+        self.target = BlinkTarget::Positions(mask);
+        self
+    }
+
+    /// Set the blink target to a single `position`.
+    pub const fn position(mut self, position: usize) -> Self {
+        self.target = BlinkTarget::position(position);
+        self
+    }
+
+    /// Set the blink target to [`BlinkTarget::Dots`].
+    pub const fn dots(mut self) -> Self {
+        self.target = BlinkTarget::Dots;
+        self
+    }
+
+    /// Finish setting the blink animation.
+    pub fn finish(self) -> Blinker<'d, N, T, CLK, DIO, DELAY, M>
+    where
+        I: Iterator<Item = u8>,
+    {
+        Blinker {
+            device: self.options.device,
+            position: self.options.position,
+            frame: Frame::new(self.options.iter),
+            on_ms: self.on_ms,
+            off_ms: self.off_ms,
+            count: self.count,
+            target: self.target,
+            _flip: self.options._flip,
+        }
+    }
+}
+
+/// Blink animation.
+///
+/// Responsible for running the animation.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Blinker<'d, const N: usize, T, CLK, DIO, DELAY, M> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    position: usize,
+    frame: Frame<N>,
+    on_ms: u32,
+    off_ms: u32,
+    count: Option<usize>,
+    target: BlinkTarget,
+    _flip: M,
+}
+
+impl<const N: usize, T, CLK, DIO, DELAY, M> Blinker<'_, N, T, CLK, DIO, DELAY, M> {
+    /// Returns the steps of the animation before flipping and aligning the frames.
+    ///
+    /// - [`BlinkTarget::Display`] writes the frame once and toggles the display on and off.
+    /// - Other targets toggle between the frame and the blanked frame.
+    /// - A finite animation ends with the frame visible.
+    fn frames(&self) -> impl Iterator<Item = (Step<Frame<N>>, u32)> {
+        let position = self.position;
+        let frame = self.frame;
+        let target = self.target;
+        let (on_ms, off_ms) = (self.on_ms, self.off_ms);
+
+        let (on, off) = match target {
+            BlinkTarget::Display => (Step::On, Step::Off),
+            BlinkTarget::Positions(_) => (
+                Step::Display(position, frame),
+                Step::Display(
+                    position,
+                    frame.map(|i, b| if target.blanks(i) { 0 } else { b }),
+                ),
+            ),
+            BlinkTarget::Dots => (
+                Step::Display(position, frame),
+                Step::Display(position, frame.map(|_, b| b & !(SegmentBits::Dot as u8))),
+            ),
+        };
+
+        let cycles = cycles(self.count);
+
+        let restore = self.count.map(|_| (on, 0));
+
+        matches!(target, BlinkTarget::Display)
+            .then_some((Step::Display(position, frame), 0))
+            .into_iter()
+            .chain(cycles.flat_map(move |_| [(on, on_ms), (off, off_ms)]))
+            .chain(restore)
+    }
+}
+
+#[::duplicate::duplicate_item(
+    module        async     await               Token                     DelayTrait                             ScrollIter            Signal                                              never;
+    [asynch]      [async]   [await.identity()]  [crate::tokens::Async]    [::embedded_hal_async::delay::DelayNs] [::futures::Stream]   [impl ::core::future::Future + Unpin + 'd]          [::core::future::pending::<()>()];
+    [blocking]    []        [identity()]        [crate::tokens::Blocking] [::embedded_hal::delay::DelayNs]       [Iterator]            [impl FnMut() -> bool + 'd]                         [|| false];
+)]
+mod module {
+    use ::embedded_hal::digital::OutputPin;
+    #[allow(unused_imports)]
+    use ::futures::StreamExt as _;
+
+    use crate::{
+        align::{Align, Aligned},
+        maybe_flipped::MaybeFlipped,
+        step::Step,
+        ConditionalInputPin, Identity, TM1637,
+    };
+
+    use super::Blinker;
+
+    #[::duplicate::duplicate_item(
+        NUM_POS ;
+        [4] ;
+        [6] ;
+    )]
+    impl<'d, CLK, DIO, DELAY, ERR, M> Blinker<'d, NUM_POS, Token, CLK, DIO, DELAY, M>
+    where
+        ERR: 'd,
+        CLK: OutputPin<Error = ERR>,
+        DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
+        DELAY: DelayTrait,
+        M: MaybeFlipped<NUM_POS> + 'd,
+    {
+        crate::runner::runner_methods!(
+            "blink animation",
+            NUM_POS,
+            [Token],
+            [async],
+            [await],
+            [ScrollIter],
+            [Signal],
+            [never],
+        );
+
+        /// Release the `device` and return the steps of the blink animation with their delays.
+        #[allow(clippy::type_complexity)]
+        fn into_steps(
+            self,
+        ) -> (
+            &'d mut TM1637<NUM_POS, Token, CLK, DIO, DELAY>,
+            impl Iterator<Item = (Step<impl Iterator<Item = u8>>, u32)>,
+        ) {
+            let steps = self.frames().map(|(step, delay_ms)| {
+                let step = step.map(|position, frame| {
+                    let (position, bytes) = M::calculate(position, frame.into_iter());
+
+                    Align::<NUM_POS>::align(position, bytes)
+                });
+
+                (step, delay_ms)
+            });
+
+            (self.device, steps)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::{
+        mappings::{str_from_byte, SegmentBits},
+        mock::Noop,
+        step::Step,
+        TM1637Builder,
+    };
+
+    use super::BlinkTarget;
+
+    fn strs(step: Step<crate::frame::Frame<4>>) -> Option<Vec<&'static str>> {
+        match step {
+            Step::Display(_, frame) => Some(frame.into_iter().map(str_from_byte).collect()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn blink_display_toggles_brightness() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let blinker = tm.options().str("HELP").blink().times(2).finish();
+        let steps = blinker.frames().collect::<Vec<_>>();
+
+        assert_eq!(steps.len(), 6);
+        assert_eq!(strs(steps[0].0), Some(vec!["H", "E", "L", "P"]));
+        assert!(matches!(steps[1], (Step::On, 500)));
+        assert!(matches!(steps[2], (Step::Off, 500)));
+        assert!(matches!(steps[5], (Step::On, 0)));
+    }
+
+    #[test]
+    fn blink_positions_blanks_bytes() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let blinker = tm
+            .options()
+            .str("1234")
+            .blink()
+            .positions(0b0110)
+            .on_ms(100)
+            .off_ms(200)
+            .times(1)
+            .finish();
+        let steps = blinker.frames().collect::<Vec<_>>();
+
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[0].1, 100);
+        assert_eq!(strs(steps[0].0), Some(vec!["1", "2", "3", "4"]));
+        assert_eq!(steps[1].1, 200);
+        assert_eq!(strs(steps[1].0), Some(vec!["1", " ", " ", "4"]));
+        assert_eq!(strs(steps[2].0), Some(vec!["1", "2", "3", "4"]));
+    }
+
+    #[test]
+    fn positions_past_the_mask_target_nothing() {
+        assert_eq!(
+            BlinkTarget::position(7),
+            BlinkTarget::Positions(0b1000_0000)
+        );
+        assert_eq!(BlinkTarget::position(8), BlinkTarget::Positions(0));
+        assert_eq!(BlinkTarget::position(usize::MAX), BlinkTarget::Positions(0));
+    }
+
+    #[test]
+    fn blink_dots_clears_dot_bits_only() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let blinker = tm.options().str("12.34").blink().dots().finish();
+        let steps = blinker.frames().collect::<Vec<_>>();
+
+        match steps[1].0 {
+            Step::Display(_, frame) => {
+                assert!(frame.into_iter().all(|b| b & SegmentBits::Dot as u8 == 0));
+            }
+            _ => panic!("expected a display step"),
+        }
+
+        assert_eq!(strs(steps[0].0), Some(vec!["1", "2.", "3", "4"]));
+    }
+
+    #[test]
+    fn blink_forever_does_not_end() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let steps = tm.options().str("1234").blink().forever().finish().steps();
+
+        assert_eq!(steps.take(1000).count(), 1000);
+    }
+}
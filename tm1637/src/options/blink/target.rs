@@ -0,0 +1,35 @@
+/// Target of a blink animation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BlinkTarget {
+    /// Blink the whole display by turning it on and off using the brightness command.
+    #[default]
+    Display,
+    /// Blink the positions set in the mask by blanking them.
+    ///
+    /// Bit `i` of the mask represents the byte at position `i`.
+    /// Positions are tied to the bytes, like [`DisplayOptions::dot`](crate::options::DisplayOptions::dot).
+    Positions(u8),
+    /// Blink the dots (or the colon) only.
+    Dots,
+}
+
+impl BlinkTarget {
+    /// Create a new [`BlinkTarget::Positions`] targeting a single `position`.
+    ///
+    /// Positions past the mask, i.e. `>= 8`, target nothing.
+    pub const fn position(position: usize) -> Self {
+        match position {
+            0..8 => BlinkTarget::Positions(1 << position),
+            _ => BlinkTarget::Positions(0),
+        }
+    }
+
+    /// Returns `true` if the byte at the given `position` is blanked in the off phase.
+    pub(crate) fn blanks(&self, position: usize) -> bool {
+        match self {
+            BlinkTarget::Positions(mask) => position < 8 && mask & (1 << position) != 0,
+            _ => false,
+        }
+    }
+}
@@ -5,10 +5,14 @@ use crate::{tokens::NotFlipped, TM1637};
 mod bits;
 mod default_options;
 mod direction;
+mod spinner;
+mod spinner_options;
 
 pub use bits::*;
 pub use default_options::RotatingCircleOptions;
 pub use direction::*;
+pub use spinner::Spinner;
+pub use spinner_options::SpinnerOptions;
 
 /// High-level API for circle animations.
 #[derive(Debug)]
@@ -27,4 +31,52 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY> CirclesDisplayOptions<'d, N, T, CLK
     pub const fn rotating(self) -> RotatingCircleOptions<'d, N, T, CLK, DIO, DELAY, NotFlipped> {
         RotatingCircleOptions::new_with_defaults(self.device, NotFlipped)
     }
+
+    /// Create a new [`SpinnerOptions`] instance for the given [`Spinner`].
+    pub const fn spinner(
+        self,
+        spinner: Spinner,
+    ) -> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, NotFlipped> {
+        SpinnerOptions::new_with_defaults(self.device, spinner, NotFlipped)
+    }
+
+    /// A single segment chasing around one digit.
+    pub const fn chase(self) -> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, NotFlipped> {
+        self.spinner(Spinner::Chase)
+    }
+
+    /// A snake running around the outer perimeter of all digits.
+    ///
+    /// The loop follows the `A` segments across the top, the `B/C` segments of the last digit,
+    /// the `D` segments back along the bottom and the `E/F` segments of the first digit.
+    ///
+    /// # Example
+    ///
+    /// A busy indicator with a snake of 4 segments.
+    ///
+    /// ```rust
+    /// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+    ///
+    /// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<6>();
+    ///
+    /// tm.circles().snake().length(4).delay_ms(80).finish().run();
+    /// ```
+    pub const fn snake(self) -> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, NotFlipped> {
+        self.spinner(Spinner::Snake)
+    }
+
+    /// A middle bar bouncing between the first and the last digit.
+    pub const fn bounce(self) -> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, NotFlipped> {
+        self.spinner(Spinner::Bounce)
+    }
+
+    /// The outer perimeter of all digits filling up.
+    pub const fn filling(self) -> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, NotFlipped> {
+        self.spinner(Spinner::Filling)
+    }
+
+    /// A dot marching across all digits.
+    pub const fn marching_dots(self) -> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, NotFlipped> {
+        self.spinner(Spinner::MarchingDots)
+    }
 }
@@ -1,8 +1,4 @@
-use crate::{
-    maybe_flipped::MaybeFlipped,
-    options::{repeat::RepeatDisplayOptions, scroll::Scroller, DisplayOptions},
-    TM1637,
-};
+use crate::{maybe_flipped::MaybeFlipped, options::scroll::Scroller, TM1637};
 
 use super::{bits::RotatingCircleBits, RotatingDirection};
 
@@ -16,6 +12,7 @@ pub struct RotatingCircleOptions<'d, const N: usize, T, CLK, DIO, DELAY, M> {
     position: usize,
     delay_ms: u32,
     direction: RotatingDirection,
+    count: Option<usize>,
     _flip: M,
 }
 
@@ -35,6 +32,7 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, M>
             position,
             delay_ms,
             direction,
+            count: Some(1),
             _flip: flip,
         }
     }
@@ -62,6 +60,20 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, M>
         self
     }
 
+    /// Repeat the animation `count` times.
+    pub const fn repeat(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Repeat the animation forever.
+    ///
+    /// See [`Scroller::until`] to stop the animation.
+    pub const fn forever(mut self) -> Self {
+        self.count = None;
+        self
+    }
+
     /// Flip the display.
     pub fn flip(self) -> RotatingCircleOptions<'d, N, T, CLK, DIO, DELAY, impl MaybeFlipped<N>>
     where
@@ -72,6 +84,7 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, M>
             position: self.position,
             delay_ms: self.delay_ms,
             direction: self.direction,
+            count: self.count,
             _flip: M::flip(),
         }
     }
@@ -86,7 +99,7 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, M>
         CLK,
         DIO,
         DELAY,
-        impl Iterator<Item = impl DoubleEndedIterator<Item = u8> + ExactSizeIterator>,
+        impl Iterator<Item = impl DoubleEndedIterator<Item = u8> + ExactSizeIterator> + Clone,
         M,
     > {
         let bytes = match self.direction {
@@ -94,15 +107,16 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, M>
             RotatingDirection::CounterClockwise => RotatingCircleBits::all_u8(),
         };
 
-        RepeatDisplayOptions::new(
-            DisplayOptions {
-                device: self.device,
-                position: self.position,
-                iter: bytes.into_iter(),
-                _flip: self._flip,
-            },
+        let iter = bytes.into_iter().map(|byte| [byte].into_iter());
+
+        Scroller::new(
+            self.device,
+            1,
+            self.position,
             self.delay_ms,
+            iter,
+            self._flip,
         )
-        .finish()
+        .cycle(self.count)
     }
 }
@@ -0,0 +1,132 @@
+use crate::{frame::Frame, mappings::SegmentBits};
+
+use super::RotatingDirection;
+
+/// Loading spinners.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Spinner {
+    /// A single segment chasing around one digit.
+    #[default]
+    Chase,
+    /// A snake running around the outer perimeter of all digits.
+    Snake,
+    /// A middle bar bouncing between the first and the last digit.
+    Bounce,
+    /// The outer perimeter of all digits filling up segment by segment, then clearing.
+    Filling,
+    /// A dot marching across all digits.
+    MarchingDots,
+}
+
+/// Outer segments of a digit in clockwise order starting from the top.
+const CIRCLE: [SegmentBits; 6] = [
+    SegmentBits::SegA,
+    SegmentBits::SegB,
+    SegmentBits::SegC,
+    SegmentBits::SegD,
+    SegmentBits::SegE,
+    SegmentBits::SegF,
+];
+
+/// Number of segments on the outer perimeter of `digits` digits.
+pub(crate) const fn perimeter_len(digits: usize) -> usize {
+    2 * digits + 4
+}
+
+/// Returns the digit and segment of the perimeter `cell` in clockwise order starting from the top left.
+pub(crate) const fn perimeter_cell(digits: usize, cell: usize) -> (usize, SegmentBits) {
+    let last = digits.saturating_sub(1);
+
+    match cell {
+        c if c < digits => (c, SegmentBits::SegA),
+        c if c == digits => (last, SegmentBits::SegB),
+        c if c == digits + 1 => (last, SegmentBits::SegC),
+        c if c < 2 * digits + 2 => (last - (c - digits - 2), SegmentBits::SegD),
+        c if c == 2 * digits + 2 => (0, SegmentBits::SegE),
+        _ => (0, SegmentBits::SegF),
+    }
+}
+
+/// Returns the `len` perimeter cells following `start` in clockwise order as a frame of `digits` digits.
+pub(crate) fn perimeter<const N: usize>(digits: usize, start: usize, len: usize) -> Frame<N> {
+    let cells = perimeter_len(digits);
+
+    (0..len.min(cells)).fold(
+        Frame::new(::core::iter::repeat_n(0, digits)),
+        |frame, offset| {
+            let (digit, segment) = perimeter_cell(digits, (start + offset) % cells);
+
+            frame.map(|i, byte| {
+                if i == digit {
+                    byte | segment as u8
+                } else {
+                    byte
+                }
+            })
+        },
+    )
+}
+
+impl Spinner {
+    /// Returns the number of frames of one cycle on `digits` digits.
+    pub(crate) const fn frames(&self, digits: usize) -> usize {
+        match self {
+            Spinner::Chase => CIRCLE.len(),
+            Spinner::Snake => perimeter_len(digits),
+            Spinner::Bounce if digits > 1 => 2 * (digits - 1),
+            Spinner::Bounce => 1,
+            Spinner::Filling => perimeter_len(digits) + 1,
+            Spinner::MarchingDots => digits,
+        }
+    }
+
+    /// Returns the frame at `index` of one cycle on `digits` digits.
+    ///
+    /// `len` is the length of the [`Spinner::Snake`].
+    pub(crate) fn frame<const N: usize>(
+        &self,
+        digits: usize,
+        direction: RotatingDirection,
+        len: usize,
+        index: usize,
+    ) -> Frame<N> {
+        let frames = self.frames(digits).max(1);
+        let index = index % frames;
+
+        let reversed = match direction {
+            RotatingDirection::Clockwise => index,
+            RotatingDirection::CounterClockwise => (frames - index) % frames,
+        };
+
+        let single = |digit: usize, segment: SegmentBits| {
+            Frame::new((0..digits).map(move |i| if i == digit { segment as u8 } else { 0 }))
+        };
+
+        match self {
+            Spinner::Chase => Frame::new([CIRCLE[reversed] as u8].into_iter()),
+            Spinner::Snake => perimeter(digits, reversed, len),
+            Spinner::Bounce => {
+                let digit = if index < digits {
+                    index
+                } else {
+                    frames - index
+                };
+
+                match direction {
+                    RotatingDirection::Clockwise => single(digit, SegmentBits::SegG),
+                    RotatingDirection::CounterClockwise => {
+                        single(digits.saturating_sub(1 + digit), SegmentBits::SegG)
+                    }
+                }
+            }
+            Spinner::Filling => match direction {
+                RotatingDirection::Clockwise => perimeter(digits, 0, index),
+                RotatingDirection::CounterClockwise => {
+                    perimeter(digits, perimeter_len(digits) - index, index)
+                }
+            },
+            Spinner::MarchingDots => single(reversed, SegmentBits::Dot),
+        }
+    }
+}
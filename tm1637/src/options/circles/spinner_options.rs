@@ -0,0 +1,252 @@
+use crate::{maybe_flipped::MaybeFlipped, options::scroll::Scroller, TM1637};
+
+use super::{RotatingDirection, Spinner};
+
+/// Loading spinner options.
+///
+/// [`Spinner::Chase`] runs on a single digit at the given position, the other spinners run across all digits from the given position on.
+///
+/// # Example
+///
+/// Run a bouncing bar twice.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.circles().bounce().delay_ms(100).repeat(2).finish().run();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SpinnerOptions<'d, const N: usize, T, CLK, DIO, DELAY, M> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    spinner: Spinner,
+    position: usize,
+    delay_ms: u32,
+    direction: RotatingDirection,
+    length: usize,
+    count: Option<usize>,
+    _flip: M,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, M> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, M> {
+    /// Create a new [`SpinnerOptions`] instance with default settings.
+    ///
+    /// Runs one clockwise cycle from the first position with a delay of 100ms between each step.
+    /// The [`Spinner::Snake`] is 3 segments long.
+    pub const fn new_with_defaults(
+        device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+        spinner: Spinner,
+        flip: M,
+    ) -> Self {
+        Self {
+            device,
+            spinner,
+            position: 0,
+            delay_ms: 100,
+            direction: RotatingDirection::Clockwise,
+            length: 3,
+            count: Some(1),
+            _flip: flip,
+        }
+    }
+
+    /// Set the position of the spinner.
+    pub const fn position(mut self, position: usize) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the delay in milliseconds between each animation step.
+    pub const fn delay_ms(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    /// Set the direction.
+    ///
+    /// [`RotatingDirection::Clockwise`] moves linear spinners from left to right.
+    pub const fn direction(mut self, direction: RotatingDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the length of the [`Spinner::Snake`] in segments.
+    ///
+    /// The length is clamped to the perimeter of the digits.
+    pub const fn length(mut self, length: usize) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Repeat the animation `count` times.
+    pub const fn repeat(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Repeat the animation forever.
+    ///
+    /// See [`Scroller::until`] to stop the animation.
+    pub const fn forever(mut self) -> Self {
+        self.count = None;
+        self
+    }
+
+    /// Flip the display.
+    pub fn flip(self) -> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, impl MaybeFlipped<N>>
+    where
+        M: MaybeFlipped<N>,
+    {
+        SpinnerOptions {
+            device: self.device,
+            spinner: self.spinner,
+            position: self.position,
+            delay_ms: self.delay_ms,
+            direction: self.direction,
+            length: self.length,
+            count: self.count,
+            _flip: M::flip(),
+        }
+    }
+
+    /// Finish setting the spinner animation.
+    pub fn finish(
+        self,
+    ) -> Scroller<
+        'd,
+        N,
+        T,
+        CLK,
+        DIO,
+        DELAY,
+        impl Iterator<Item = impl DoubleEndedIterator<Item = u8> + ExactSizeIterator> + Clone,
+        M,
+    > {
+        let (spinner, direction, length) = (self.spinner, self.direction, self.length);
+
+        let digits = match spinner {
+            Spinner::Chase => 1,
+            _ => N.saturating_sub(self.position),
+        };
+
+        let iter = (0..spinner.frames(digits)).map(move |index| {
+            spinner
+                .frame::<N>(digits, direction, length, index)
+                .into_iter()
+        });
+
+        Scroller::new(
+            self.device,
+            digits,
+            self.position,
+            self.delay_ms,
+            iter,
+            self._flip,
+        )
+        .cycle(self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::mappings::SegmentBits;
+
+    use super::*;
+
+    fn frames(spinner: Spinner, direction: RotatingDirection, digits: usize) -> Vec<Vec<u8>> {
+        (0..spinner.frames(digits))
+            .map(|index| {
+                spinner
+                    .frame::<4>(digits, direction, 3, index)
+                    .into_iter()
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn snake_runs_around_the_perimeter() {
+        let a = SegmentBits::SegA as u8;
+        let b = SegmentBits::SegB as u8;
+        let d = SegmentBits::SegD as u8;
+        let f = SegmentBits::SegF as u8;
+
+        let snake = frames(Spinner::Snake, RotatingDirection::Clockwise, 2);
+
+        assert_eq!(snake.len(), 8);
+        assert_eq!(snake[0], vec![a, a | b]);
+        assert_eq!(snake[7], vec![a | f, a]);
+        assert_eq!(snake[4], vec![d | SegmentBits::SegE as u8, d]);
+    }
+
+    #[test]
+    fn snake_respects_the_order_of_6_digit_displays() {
+        use crate::align::{Align, Aligned};
+
+        let a = SegmentBits::SegA as u8;
+        let b = SegmentBits::SegB as u8;
+
+        let head = Spinner::Snake.frame::<6>(6, RotatingDirection::Clockwise, 2, 5);
+
+        assert_eq!(
+            head.into_iter().collect::<Vec<_>>(),
+            vec![0, 0, 0, 0, 0, a | b]
+        );
+
+        // The bytes stay in order, the profile maps the last digit to its grid.
+        let (_, bytes) = Align::<6>::align(0, head.into_iter());
+
+        assert_eq!(bytes.collect::<Vec<_>>(), vec![0, 0, 0, 0, 0, a | b]);
+        assert_eq!(crate::BoardProfile::SIX_DIGIT.address(5), 3);
+    }
+
+    #[test]
+    fn snake_length_is_clamped() {
+        let snake = frames(Spinner::Snake, RotatingDirection::CounterClockwise, 1);
+
+        assert_eq!(snake[1], vec![0b0010_0011]);
+        assert_eq!(
+            Spinner::Snake
+                .frame::<4>(1, RotatingDirection::Clockwise, 10, 0)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![0b0011_1111]
+        );
+    }
+
+    #[test]
+    fn linear_spinners() {
+        let g = SegmentBits::SegG as u8;
+        let dot = SegmentBits::Dot as u8;
+
+        let bounce = frames(Spinner::Bounce, RotatingDirection::Clockwise, 3);
+
+        assert_eq!(
+            bounce,
+            vec![vec![g, 0, 0], vec![0, g, 0], vec![0, 0, g], vec![0, g, 0]]
+        );
+
+        let dots = frames(
+            Spinner::MarchingDots,
+            RotatingDirection::CounterClockwise,
+            3,
+        );
+
+        assert_eq!(
+            dots,
+            vec![vec![dot, 0, 0], vec![0, 0, dot], vec![0, dot, 0]]
+        );
+
+        let filling = frames(Spinner::Filling, RotatingDirection::Clockwise, 1);
+
+        assert_eq!(filling.len(), 7);
+        assert_eq!(filling[0], vec![0]);
+        assert_eq!(filling[6], vec![0b0011_1111]);
+    }
+}
@@ -1,6 +1,17 @@
-use crate::formatters::clock_to_4digits;
+//! Clock settings.
+
+mod error;
+mod format;
+mod layout;
+mod runner;
+mod time;
 
 use super::DisplayOptions;
+pub use error::ClockError;
+use format::ClockFormat;
+pub use layout::ClockLayout;
+pub use runner::*;
+pub use time::{Time, TimeSource};
 
 /// High-level API for setting a clock.
 ///
@@ -17,9 +28,9 @@ use super::DisplayOptions;
 ///     .clock()
 ///     .hour(14)
 ///     .minute(28)
-///     .finish()
 ///     // Set the colon between the hours and minutes.
-///     .dot(1)
+///     .colon(true)
+///     .finish()
 ///     .display()
 ///     .ok();
 /// ```
@@ -31,39 +42,166 @@ use super::DisplayOptions;
 /// | 1 | | 4 |:| 2 | | 8 |
 /// +---+ +---+ +---+ +---+
 /// ```
+///
+/// Display the time `09:05:30 PM` on a 6-digit display.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<6>();
+///
+/// tm.options()
+///     .clock()
+///     .hour(21)
+///     .minute(5)
+///     .second(30)
+///     .hour_minute_second()
+///     .twelve_hour()
+///     .leading_zero(true)
+///     .colon(true)
+///     .try_finish()
+///     .expect("Valid time")
+///     .display()
+///     .ok();
+/// ```
+///
+/// The display will show:
+///
+/// ```text
+/// +---+ +---+ +---+ +---+ +---+ +---+
+/// | 0 | | 9 |:| 0 | | 5 |:| 3 | | 0.|
+/// +---+ +---+ +---+ +---+ +---+ +---+
+/// ```
 #[derive(Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ClockDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, M> {
     options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
-    hour: u8,
-    minute: u8,
+    time: Time,
+    format: ClockFormat,
 }
 
 impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
     ClockDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>
 {
     /// Create a new [`ClockDisplayOptions`] instance.
+    ///
+    /// Uses the 24-hour [`ClockLayout::HourMinute`] layout without a leading zero and without a colon.
     pub const fn new(options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>) -> Self {
+        let mut format = ClockFormat::new();
+
+        if let Some(position) = options.device.profile().colon() {
+            format.colon_position = position;
+        }
+
         Self {
             options,
-            hour: 0,
-            minute: 0,
+            time: Time::new(0, 0, 0),
+            format,
         }
     }
 
     /// Set the hour.
+    ///
+    /// The hour is always given in the 24-hour format.
     pub const fn hour(mut self, hour: u8) -> Self {
-        self.hour = hour;
+        self.time.hour = hour;
         self
     }
 
     /// Set the minute.
     pub const fn minute(mut self, minute: u8) -> Self {
-        self.minute = minute;
+        self.time.minute = minute;
+        self
+    }
+
+    /// Set the second.
+    pub const fn second(mut self, second: u8) -> Self {
+        self.time.second = second;
+        self
+    }
+
+    /// Set the layout.
+    pub const fn layout(mut self, layout: ClockLayout) -> Self {
+        self.format.layout = layout;
+        self
+    }
+
+    /// Use the [`ClockLayout::HourMinute`] layout.
+    pub const fn hour_minute(self) -> Self {
+        self.layout(ClockLayout::HourMinute)
+    }
+
+    /// Use the [`ClockLayout::HourMinuteSecond`] layout.
+    pub const fn hour_minute_second(self) -> Self {
+        self.layout(ClockLayout::HourMinuteSecond)
+    }
+
+    /// Use the [`ClockLayout::MinuteSecond`] layout.
+    pub const fn minute_second(self) -> Self {
+        self.layout(ClockLayout::MinuteSecond)
+    }
+
+    /// Use the 12-hour format.
+    ///
+    /// `PM` is indicated by a dot, see [`ClockDisplayOptions::pm_dot`].
+    pub const fn twelve_hour(mut self) -> Self {
+        self.format.twelve_hour = true;
+        self
+    }
+
+    /// Use the 24-hour format.
+    pub const fn twenty_four_hour(mut self) -> Self {
+        self.format.twelve_hour = false;
+        self
+    }
+
+    /// Show or hide the leading zero of the first two digits.
+    pub const fn leading_zero(mut self, leading_zero: bool) -> Self {
+        self.format.leading_zero = leading_zero;
+        self
+    }
+
+    /// Show or hide the colon between the hours, minutes and seconds.
+    pub const fn colon(mut self, colon: bool) -> Self {
+        self.format.colon = colon;
+        self
+    }
+
+    /// Set the position of the dot that is wired to the colon on your board.
+    ///
+    /// Defaults to the colon of the [`BoardProfile`](crate::BoardProfile) or `1`. The [`ClockLayout::HourMinuteSecond`] layout uses the dot two positions further as the second colon.
+    pub const fn colon_position(mut self, position: usize) -> Self {
+        self.format.colon_position = position;
+        self
+    }
+
+    /// Set the position of the dot that indicates `PM` in the 12-hour format.
+    ///
+    /// Defaults to the last digit of the layout.
+    pub const fn pm_dot(mut self, position: usize) -> Self {
+        self.format.pm_position = Some(position);
         self
     }
 
+    /// Run the clock using the time of the given [`TimeSource`].
+    ///
+    /// The time set using [`ClockDisplayOptions::hour`], [`ClockDisplayOptions::minute`] and [`ClockDisplayOptions::second`] is ignored.
+    pub fn time_source<S: TimeSource>(
+        self,
+        source: S,
+    ) -> ClockOptions<'d, N, T, CLK, DIO, DELAY, M, S> {
+        ClockOptions::new(
+            self.options.device,
+            self.options.position,
+            source,
+            self.format,
+            self.options._flip,
+        )
+    }
+
     /// Finish setting the clock.
+    ///
+    /// Invalid times are displayed as dashes, see [`ClockDisplayOptions::try_finish`].
     pub fn finish(
         self,
     ) -> DisplayOptions<
@@ -79,7 +217,134 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
     where
         I: DoubleEndedIterator<Item = u8> + ExactSizeIterator,
     {
-        self.options
-            .iter(clock_to_4digits(self.hour, self.minute, false).into_iter())
+        let bytes = self.format.bytes(self.time);
+        let len = self.format.layout.digits();
+
+        self.options.iter(bytes.into_iter().take(len))
+    }
+
+    /// Finish setting the clock.
+    ///
+    /// Returns an error if the time is invalid.
+    pub fn try_finish(
+        self,
+    ) -> Result<
+        DisplayOptions<
+            'd,
+            N,
+            T,
+            CLK,
+            DIO,
+            DELAY,
+            impl DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+            M,
+        >,
+        ClockError,
+    >
+    where
+        I: DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+    {
+        self.format.validate(self.time)?;
+
+        Ok(self.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::{mappings::str_from_byte, mock::Noop, TM1637Builder};
+
+    use super::*;
+
+    #[test]
+    fn twelve_hour_with_pm_dot() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let (_, iter) = tm
+            .options()
+            .clock()
+            .hour(13)
+            .minute(7)
+            .twelve_hour()
+            .colon(true)
+            .finish()
+            .calculate();
+
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(vec![" ", "1.", "0", "7."], collected);
+
+        let (_, iter) = tm
+            .options()
+            .clock()
+            .hour(0)
+            .minute(30)
+            .twelve_hour()
+            .finish()
+            .calculate();
+
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(vec!["1", "2", "3", "0"], collected);
+    }
+
+    #[test]
+    fn hour_minute_second_and_minute_second() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<6>();
+
+        let (_, iter) = tm
+            .options()
+            .clock()
+            .hour(9)
+            .minute(5)
+            .second(3)
+            .hour_minute_second()
+            .leading_zero(true)
+            .colon(true)
+            .finish()
+            .calculate();
+
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(vec!["0", "9.", "0", "5.", "0", "3"], collected);
+
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let (_, iter) = tm
+            .options()
+            .clock()
+            .minute(5)
+            .second(3)
+            .minute_second()
+            .colon(true)
+            .colon_position(2)
+            .finish()
+            .calculate();
+
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(vec![" ", "5", "0.", "3"], collected);
+    }
+
+    #[test]
+    fn invalid_time_is_rejected() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let error = tm.options().clock().hour(24).try_finish().err();
+
+        assert_eq!(error, Some(ClockError::InvalidHour(24)));
+
+        let error = tm.options().clock().minute(60).try_finish().err();
+
+        assert_eq!(error, Some(ClockError::InvalidMinute(60)));
+
+        let (_, iter) = tm.options().clock().hour(24).finish().calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(vec!["-", "-", "-", "-"], collected);
     }
 }
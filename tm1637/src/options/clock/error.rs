@@ -0,0 +1,11 @@
+/// Error returned when a clock is set to an invalid time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ClockError {
+    /// The hour is greater than `23`.
+    InvalidHour(u8),
+    /// The minute is greater than `59`.
+    InvalidMinute(u8),
+    /// The second is greater than `59`.
+    InvalidSecond(u8),
+}
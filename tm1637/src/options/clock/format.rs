@@ -0,0 +1,113 @@
+use crate::mappings::{DigitBits, SegmentBits};
+
+use super::{ClockError, ClockLayout, Time};
+
+/// Settings for formatting a [`Time`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct ClockFormat {
+    pub(crate) layout: ClockLayout,
+    pub(crate) twelve_hour: bool,
+    pub(crate) leading_zero: bool,
+    pub(crate) colon: bool,
+    pub(crate) colon_position: usize,
+    pub(crate) pm_position: Option<usize>,
+}
+
+impl ClockFormat {
+    /// Create a new [`ClockFormat`] with the 24-hour [`ClockLayout::HourMinute`] layout, without a leading zero and without a colon.
+    pub(crate) const fn new() -> Self {
+        Self {
+            layout: ClockLayout::HourMinute,
+            twelve_hour: false,
+            leading_zero: false,
+            colon: false,
+            colon_position: 1,
+            pm_position: None,
+        }
+    }
+
+    /// Validate the fields of the `time` used by the layout.
+    pub(crate) const fn validate(&self, time: Time) -> Result<(), ClockError> {
+        if !matches!(self.layout, ClockLayout::MinuteSecond) && time.hour > 23 {
+            return Err(ClockError::InvalidHour(time.hour));
+        }
+
+        if time.minute > 59 {
+            return Err(ClockError::InvalidMinute(time.minute));
+        }
+
+        if !matches!(self.layout, ClockLayout::HourMinute) && time.second > 59 {
+            return Err(ClockError::InvalidSecond(time.second));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the bytes of the `time`, only the first [`ClockLayout::digits`] bytes are used.
+    ///
+    /// Invalid times are formatted as dashes.
+    pub(crate) fn bytes(&self, time: Time) -> [u8; 6] {
+        let dot = SegmentBits::Dot as u8;
+        let len = self.layout.digits();
+        let mut bytes = [0; 6];
+
+        if self.validate(time).is_err() {
+            bytes[..len].fill(SegmentBits::SegG as u8);
+
+            return bytes;
+        }
+
+        let hour = match self.twelve_hour {
+            true if time.hour % 12 == 0 => 12,
+            true => time.hour % 12,
+            false => time.hour,
+        };
+
+        let (first, second, third) = match self.layout {
+            ClockLayout::HourMinute => (hour, time.minute, None),
+            ClockLayout::HourMinuteSecond => (hour, time.minute, Some(time.second)),
+            ClockLayout::MinuteSecond => (time.minute, time.second, None),
+        };
+
+        if first >= 10 || self.leading_zero {
+            bytes[0] = DigitBits::from_digit(first / 10) as u8;
+        }
+
+        bytes[1] = DigitBits::from_digit(first % 10) as u8;
+        bytes[2] = DigitBits::from_digit(second / 10) as u8;
+        bytes[3] = DigitBits::from_digit(second % 10) as u8;
+
+        if let Some(third) = third {
+            bytes[4] = DigitBits::from_digit(third / 10) as u8;
+            bytes[5] = DigitBits::from_digit(third % 10) as u8;
+        }
+
+        if self.colon {
+            let colons = match self.layout {
+                ClockLayout::HourMinuteSecond => [Some(0), Some(2)],
+                _ => [Some(0), None],
+            };
+
+            for position in colons.into_iter().flatten() {
+                if let Some(byte) = bytes[..len].get_mut(self.colon_position + position) {
+                    *byte |= dot;
+                }
+            }
+        }
+
+        let pm = self.twelve_hour
+            && time.hour >= 12
+            && !matches!(self.layout, ClockLayout::MinuteSecond);
+
+        if pm {
+            let position = self.pm_position.unwrap_or(len - 1);
+
+            if let Some(byte) = bytes[..len].get_mut(position) {
+                *byte |= dot;
+            }
+        }
+
+        bytes
+    }
+}
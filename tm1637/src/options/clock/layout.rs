@@ -0,0 +1,22 @@
+/// Layout of the digits of a clock.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ClockLayout {
+    /// `HH:MM` on 4 digits.
+    #[default]
+    HourMinute,
+    /// `HH:MM:SS` on 6 digits.
+    HourMinuteSecond,
+    /// `MM:SS` on 4 digits. Useful for timers.
+    MinuteSecond,
+}
+
+impl ClockLayout {
+    /// Returns the number of digits used by the layout.
+    pub const fn digits(&self) -> usize {
+        match self {
+            ClockLayout::HourMinute | ClockLayout::MinuteSecond => 4,
+            ClockLayout::HourMinuteSecond => 6,
+        }
+    }
+}
@@ -0,0 +1,513 @@
+use crate::{frame::Frame, options::DateLayout, step::Step, Brightness, TM1637};
+
+use super::{ClockFormat, ClockLayout, Time, TimeSource};
+
+/// High-level API for a self-running clock.
+///
+/// Created using [`ClockDisplayOptions::time_source`](super::ClockDisplayOptions::time_source).
+///
+/// # Example
+///
+/// Run a clock with a blinking colon that dims the display between `22:00` and `07:00`.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, options::Time, Brightness, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.options()
+///     .clock()
+///     .time_source(|| Time::new(23, 15, 0))
+///     .night(22, 7, Brightness::L0)
+///     .finish()
+///     .steps()
+///     // Runs forever
+///     .take(4)
+///     .for_each(|_| {});
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ClockOptions<'d, const N: usize, T, CLK, DIO, DELAY, M, S> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    position: usize,
+    source: S,
+    format: ClockFormat,
+    blink_colon: bool,
+    night: Option<Night>,
+    alternation: Option<Alternation>,
+    _flip: M,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, M, S> ClockOptions<'d, N, T, CLK, DIO, DELAY, M, S> {
+    /// Create a new [`ClockOptions`] instance.
+    ///
+    /// The colon blinks at 1 Hz and the display is not dimmed at night.
+    pub(crate) const fn new(
+        device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+        position: usize,
+        source: S,
+        format: ClockFormat,
+        flip: M,
+    ) -> Self {
+        Self {
+            device,
+            position,
+            source,
+            format,
+            blink_colon: true,
+            night: None,
+            alternation: None,
+            _flip: flip,
+        }
+    }
+
+    /// Blink the colon at 1 Hz or show it permanently.
+    ///
+    /// Without blinking, the display is updated once per second if the layout shows the seconds, otherwise once per minute.
+    pub const fn blink_colon(mut self, blink_colon: bool) -> Self {
+        self.blink_colon = blink_colon;
+        self
+    }
+
+    /// Dim the display to `brightness` from `from_hour` until `to_hour`.
+    ///
+    /// The range may wrap around midnight, e.g. `22` to `7`.
+    /// The brightness of the device at [`ClockOptions::finish`] is restored during the day.
+    ///
+    /// The clock does not restore the brightness when it is stopped, e.g. using `run_until`.
+    /// If it is stopped at night, the device keeps the night `brightness`, see [`TM1637::set_brightness`].
+    pub const fn night(mut self, from_hour: u8, to_hour: u8, brightness: Brightness) -> Self {
+        self.night = Some(Night {
+            from_hour,
+            to_hour,
+            brightness,
+        });
+        self
+    }
+
+    /// Alternate between showing the time for `time_ms` and the date for `date_ms` milliseconds.
+    ///
+    /// The date is read using [`TimeSource::date`]. The time is shown permanently if the source has no date or `date_ms` is `0`.
+    pub const fn alternate_date(mut self, layout: DateLayout, time_ms: u32, date_ms: u32) -> Self {
+        self.alternation = match date_ms {
+            0 => None,
+            _ => Some(Alternation {
+                layout,
+                time_ms,
+                date_ms,
+            }),
+        };
+        self
+    }
+
+    /// Finish setting the clock.
+    pub fn finish(self) -> Clock<'d, N, T, CLK, DIO, DELAY, M, S> {
+        let day = self.device.brightness();
+
+        Clock {
+            device: self.device,
+            position: self.position,
+            ticks: Ticks {
+                source: self.source,
+                format: self.format,
+                blink_colon: self.blink_colon,
+                night: self.night,
+                alternation: self.alternation,
+                elapsed_ms: 0,
+                day,
+                brightness: day,
+                pending: None,
+            },
+            _flip: self._flip,
+        }
+    }
+}
+
+/// Night-time dimming.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Night {
+    from_hour: u8,
+    to_hour: u8,
+    brightness: Brightness,
+}
+
+impl Night {
+    /// Returns `true` if the `hour` is within the night.
+    const fn contains(&self, hour: u8) -> bool {
+        match self.from_hour <= self.to_hour {
+            true => hour >= self.from_hour && hour < self.to_hour,
+            false => hour >= self.from_hour || hour < self.to_hour,
+        }
+    }
+}
+
+/// Alternation between the time and the date.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Alternation {
+    layout: DateLayout,
+    time_ms: u32,
+    date_ms: u32,
+}
+
+impl Alternation {
+    const fn period_ms(&self) -> u32 {
+        self.time_ms.saturating_add(self.date_ms)
+    }
+}
+
+/// Self-running clock.
+///
+/// Responsible for running the clock. The clock runs forever.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Clock<'d, const N: usize, T, CLK, DIO, DELAY, M, S> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    position: usize,
+    ticks: Ticks<N, S>,
+    _flip: M,
+}
+
+/// Endless steps of a [`Clock`] before flipping and aligning the frames.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Ticks<const N: usize, S> {
+    source: S,
+    format: ClockFormat,
+    blink_colon: bool,
+    night: Option<Night>,
+    alternation: Option<Alternation>,
+    /// Elapsed milliseconds within the period of the [`Alternation`].
+    elapsed_ms: u32,
+    day: Brightness,
+    brightness: Brightness,
+    /// Step to yield before reading the time again.
+    pending: Option<(Step<Frame<N>>, u32)>,
+}
+
+impl<const N: usize, S: TimeSource> Ticks<N, S> {
+    fn frame(&self, time: Time, colon: bool) -> Frame<N> {
+        let format = ClockFormat {
+            colon,
+            ..self.format
+        };
+
+        Frame::new(format.bytes(time).into_iter().take(format.layout.digits()))
+    }
+
+    /// Returns the step hiding the colons, writing only the digits from the first colon to the digit after the last one.
+    ///
+    /// The digit after a colon is written as it shows the colon if the orientation reverses the digits.
+    fn colon_off(&self, time: Time) -> Step<Frame<N>> {
+        let frame = self.frame(time, false);
+        let first = self.format.colon_position;
+        let last = match self.format.layout {
+            ClockLayout::HourMinuteSecond => first + 2,
+            _ => first,
+        };
+
+        let digits = (first..(last + 2).min(frame.len())).map(|position| frame.get(position));
+
+        Step::Display(first, Frame::new(digits))
+    }
+
+    /// Advance the elapsed milliseconds within the period of the [`Alternation`].
+    fn advance(&mut self, delay_ms: u32) {
+        if let Some(alternation) = self.alternation {
+            self.elapsed_ms = (self.elapsed_ms + delay_ms) % alternation.period_ms().max(1);
+        }
+    }
+}
+
+impl<const N: usize, S: TimeSource> Iterator for Ticks<N, S> {
+    type Item = (Step<Frame<N>>, u32);
+
+    /// - With a blinking colon, the time is read and written every second and the colon is hidden after 500ms.
+    /// - Otherwise the time is written once per second or once per minute depending on the layout.
+    /// - The brightness is written before reading the time again when entering or leaving the night.
+    /// - When alternating, the date is written once for the rest of the period and delays are cut at the switch.
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pending) = self.pending.take() {
+            return Some(pending);
+        }
+
+        let time = self.source.now();
+
+        let brightness = match self.night {
+            Some(night) if night.contains(time.hour) => night.brightness,
+            _ => self.day,
+        };
+
+        if brightness != self.brightness {
+            self.brightness = brightness;
+
+            return Some((Step::Brightness(brightness), 0));
+        }
+
+        let remaining_ms = match self.alternation {
+            Some(alternation) if self.elapsed_ms >= alternation.time_ms => {
+                if let Some(date) = self.source.date() {
+                    let delay_ms = alternation.period_ms() - self.elapsed_ms;
+                    let frame = date.frame(alternation.layout);
+
+                    self.advance(delay_ms);
+
+                    return Some((Step::Display(0, Frame::new(frame.into_iter())), delay_ms));
+                }
+
+                u32::MAX
+            }
+            Some(alternation) => alternation.time_ms - self.elapsed_ms,
+            None => u32::MAX,
+        };
+
+        match self.blink_colon {
+            true => {
+                let delay_ms = 500.min(remaining_ms);
+                let off_ms = 500.min(remaining_ms - delay_ms);
+
+                self.advance(delay_ms + off_ms);
+
+                if off_ms > 0 {
+                    self.pending = Some((self.colon_off(time), off_ms));
+                }
+
+                Some((Step::Display(0, self.frame(time, true)), delay_ms))
+            }
+            false => {
+                let delay_ms = match self.format.layout {
+                    ClockLayout::HourMinute => (60 - time.second.min(59) as u32) * 1000,
+                    _ => 1000,
+                }
+                .min(remaining_ms);
+
+                self.advance(delay_ms);
+
+                Some((
+                    Step::Display(0, self.frame(time, self.format.colon)),
+                    delay_ms,
+                ))
+            }
+        }
+    }
+}
+
+#[::duplicate::duplicate_item(
+    module        async     await               Token                     DelayTrait                             ScrollIter            Signal                                              never;
+    [asynch]      [async]   [await.identity()]  [crate::tokens::Async]    [::embedded_hal_async::delay::DelayNs] [::futures::Stream]   [impl ::core::future::Future + Unpin + 'd]          [::core::future::pending::<()>()];
+    [blocking]    []        [identity()]        [crate::tokens::Blocking] [::embedded_hal::delay::DelayNs]       [Iterator]            [impl FnMut() -> bool + 'd]                         [|| false];
+)]
+mod module {
+    use ::embedded_hal::digital::OutputPin;
+    #[allow(unused_imports)]
+    use ::futures::StreamExt as _;
+
+    use crate::{
+        align::{Align, Aligned},
+        maybe_flipped::MaybeFlipped,
+        options::clock::TimeSource,
+        step::Step,
+        ConditionalInputPin, Identity, TM1637,
+    };
+
+    use super::Clock;
+
+    #[::duplicate::duplicate_item(
+        NUM_POS ;
+        [4] ;
+        [6] ;
+    )]
+    impl<'d, CLK, DIO, DELAY, ERR, M, S> Clock<'d, NUM_POS, Token, CLK, DIO, DELAY, M, S>
+    where
+        ERR: 'd,
+        CLK: OutputPin<Error = ERR>,
+        DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
+        DELAY: DelayTrait,
+        M: MaybeFlipped<NUM_POS> + 'd,
+        S: TimeSource + 'd,
+    {
+        crate::runner::runner_methods!(
+            "clock",
+            NUM_POS,
+            [Token],
+            [async],
+            [await],
+            [ScrollIter],
+            [Signal],
+            [never],
+        );
+
+        /// Release the `device` and return the steps of the clock with their delays.
+        #[allow(clippy::type_complexity)]
+        fn into_steps(
+            self,
+        ) -> (
+            &'d mut TM1637<NUM_POS, Token, CLK, DIO, DELAY>,
+            impl Iterator<Item = (Step<impl Iterator<Item = u8>>, u32)>,
+        ) {
+            let position = self.position;
+
+            let steps = self.ticks.map(move |(step, delay_ms)| {
+                let step = step.map(|offset, frame| {
+                    let (position, bytes) = M::calculate(position + offset, frame.into_iter());
+
+                    Align::<NUM_POS>::align(position, bytes)
+                });
+
+                (step, delay_ms)
+            });
+
+            (self.device, steps)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::{mappings::str_from_byte, mock::Noop, options::Date, Brightness, TM1637Builder};
+
+    use super::*;
+
+    fn strs(step: &Step<Frame<4>>) -> Option<Vec<&'static str>> {
+        match step {
+            Step::Display(_, frame) => Some(frame.into_iter().map(str_from_byte).collect()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn colon_blinks_at_one_hertz() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let clock = tm
+            .options()
+            .clock()
+            .time_source(|| Time::new(12, 34, 56))
+            .finish();
+        let steps = clock.ticks.take(4).collect::<Vec<_>>();
+
+        assert_eq!(strs(&steps[0].0), Some(vec!["1", "2.", "3", "4"]));
+        // Only the colon digit and the following one are rewritten.
+        assert!(matches!(steps[1].0, Step::Display(1, _)));
+        assert_eq!(strs(&steps[1].0), Some(vec!["2", "3"]));
+        assert!(steps.iter().all(|(_, delay_ms)| *delay_ms == 500));
+    }
+
+    #[test]
+    fn colons_of_six_digits_are_hidden() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<6>();
+
+        let clock = tm
+            .options()
+            .clock()
+            .hour_minute_second()
+            .time_source(|| Time::new(12, 34, 56))
+            .finish();
+        let steps = clock.ticks.take(2).collect::<Vec<_>>();
+
+        match &steps[1].0 {
+            Step::Display(position, frame) => {
+                assert_eq!(*position, 1);
+                assert!(frame
+                    .into_iter()
+                    .map(str_from_byte)
+                    .eq(["2", "3", "4", "5"]));
+            }
+            _ => panic!("expected a display step"),
+        }
+    }
+
+    #[test]
+    fn zero_date_duration_shows_the_time_permanently() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let clock = tm
+            .options()
+            .clock()
+            .time_source(|| Time::new(12, 34, 0))
+            .alternate_date(DateLayout::DayMonth, 0, 0)
+            .finish();
+
+        assert!(clock.ticks.alternation.is_none());
+    }
+
+    #[test]
+    fn updates_once_per_minute_without_blinking() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let clock = tm
+            .options()
+            .clock()
+            .colon(true)
+            .time_source(|| Time::new(12, 34, 45))
+            .blink_colon(false)
+            .finish();
+        let steps = clock.ticks.take(2).collect::<Vec<_>>();
+
+        assert_eq!(strs(&steps[0].0), Some(vec!["1", "2.", "3", "4"]));
+        assert_eq!(steps[0].1, 15_000);
+    }
+
+    #[test]
+    fn alternates_between_time_and_date() {
+        struct Rtc;
+
+        impl TimeSource for Rtc {
+            fn now(&mut self) -> Time {
+                Time::new(12, 34, 0)
+            }
+
+            fn date(&mut self) -> Option<Date> {
+                Some(Date::new(2026, 10, 18))
+            }
+        }
+
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let clock = tm
+            .options()
+            .clock()
+            .time_source(Rtc)
+            .alternate_date(DateLayout::DayMonth, 1_500, 2_000)
+            .finish();
+        let steps = clock.ticks.take(5).collect::<Vec<_>>();
+
+        assert_eq!(steps[0].1 + steps[1].1, 1_000);
+        assert_eq!(steps[2].1, 500);
+        assert_eq!(strs(&steps[3].0), Some(vec!["1", "8.", "1", "0"]));
+        assert_eq!(steps[3].1, 2_000);
+        assert_eq!(strs(&steps[4].0), Some(vec!["1", "2.", "3", "4"]));
+    }
+
+    #[test]
+    fn dims_at_night() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop)
+            .brightness(Brightness::L5)
+            .build_blocking::<4>();
+
+        let mut hours = [21, 22, 6, 7].into_iter();
+
+        let clock = tm
+            .options()
+            .clock()
+            .time_source(move || Time::new(hours.next().unwrap_or(7), 0, 0))
+            .blink_colon(false)
+            .night(22, 7, Brightness::L0)
+            .finish();
+        let brightness = clock
+            .ticks
+            .filter_map(|(step, _)| match step {
+                Step::Brightness(brightness) => Some(brightness),
+                _ => None,
+            })
+            .take(2)
+            .collect::<Vec<_>>();
+
+        assert_eq!(brightness, vec![Brightness::L0, Brightness::L5]);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::options::Date;
+
+/// Time of the day.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Time {
+    /// Hour in the 24-hour format.
+    pub hour: u8,
+    /// Minute.
+    pub minute: u8,
+    /// Second.
+    pub second: u8,
+}
+
+impl Time {
+    /// Create a new [`Time`] instance.
+    pub const fn new(hour: u8, minute: u8, second: u8) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+        }
+    }
+}
+
+/// Source of the current time of a [`Clock`](super::Clock).
+///
+/// Implement this trait for your RTC or any other clock.
+/// Closures returning a [`Time`] implement this trait.
+///
+/// # Example
+///
+/// ```rust
+/// use tm1637_embedded_hal::options::{Time, TimeSource};
+///
+/// struct Rtc {
+///     seconds: u32,
+/// }
+///
+/// impl TimeSource for Rtc {
+///     fn now(&mut self) -> Time {
+///         let seconds = self.seconds % 86_400;
+///
+///         Time::new(
+///             (seconds / 3600) as u8,
+///             (seconds / 60 % 60) as u8,
+///             (seconds % 60) as u8,
+///         )
+///     }
+/// }
+/// ```
+pub trait TimeSource {
+    /// Returns the current time.
+    fn now(&mut self) -> Time;
+
+    /// Returns the current date if the source knows it.
+    ///
+    /// Used by [`ClockOptions::alternate_date`](super::ClockOptions::alternate_date).
+    fn date(&mut self) -> Option<Date> {
+        None
+    }
+}
+
+impl<F: FnMut() -> Time> TimeSource for F {
+    fn now(&mut self) -> Time {
+        self()
+    }
+}
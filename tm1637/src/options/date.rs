@@ -0,0 +1,230 @@
+//! Date settings.
+
+use crate::{
+    formatters::{date_to_4digits, date_to_6digits, padded_2digits},
+    frame::Frame,
+    mappings::SegmentBits,
+};
+
+mod layout;
+
+use super::DisplayOptions;
+pub use layout::DateLayout;
+
+/// Calendar date.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Date {
+    /// Year.
+    pub year: u16,
+    /// Month from `1` to `12`.
+    pub month: u8,
+    /// Day of the month from `1` to `31`.
+    pub day: u8,
+}
+
+impl Date {
+    /// Create a new [`Date`] instance.
+    pub const fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Returns `true` if the day and month are in range.
+    const fn is_valid(&self) -> bool {
+        self.month >= 1 && self.month <= 12 && self.day >= 1 && self.day <= 31
+    }
+
+    /// Returns the bytes of the date using the given `layout`.
+    ///
+    /// Invalid dates are displayed as dashes.
+    pub(crate) fn frame(&self, layout: DateLayout) -> Frame<6> {
+        if !self.is_valid() && !matches!(layout, DateLayout::Year) {
+            return Frame::new(::core::iter::repeat_n(
+                SegmentBits::SegG as u8,
+                layout.digits(),
+            ));
+        }
+
+        match layout {
+            DateLayout::DayMonth => Frame::new(date_to_4digits(self.day, self.month).into_iter()),
+            DateLayout::MonthDay => Frame::new(date_to_4digits(self.month, self.day).into_iter()),
+            DateLayout::Year => {
+                let year = self.year.min(9999);
+                let [y0, y1] = padded_2digits((year / 100) as u8);
+                let [y2, y3] = padded_2digits((year % 100) as u8);
+
+                Frame::new([y0, y1, y2, y3].into_iter())
+            }
+            DateLayout::DayMonthYear => {
+                Frame::new(date_to_6digits(self.day, self.month, self.year).into_iter())
+            }
+        }
+    }
+}
+
+/// High-level API for setting a date.
+///
+/// # Example
+///
+/// Display the date `18.10` on the display.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.options()
+///     .date()
+///     .day(18)
+///     .month(10)
+///     .finish()
+///     .display()
+///     .ok();
+/// ```
+///
+/// The display will show:
+///
+/// ```text
+/// +---+ +---+ +---+ +---+
+/// | 1 | | 8.| | 1 | | 0 |
+/// +---+ +---+ +---+ +---+
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DateDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, M> {
+    options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+    date: Date,
+    layout: DateLayout,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
+    DateDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>
+{
+    /// Create a new [`DateDisplayOptions`] instance.
+    ///
+    /// Uses the [`DateLayout::DayMonth`] layout.
+    pub const fn new(options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>) -> Self {
+        Self {
+            options,
+            date: Date::new(0, 1, 1),
+            layout: DateLayout::DayMonth,
+        }
+    }
+
+    /// Set the day.
+    pub const fn day(mut self, day: u8) -> Self {
+        self.date.day = day;
+        self
+    }
+
+    /// Set the month.
+    pub const fn month(mut self, month: u8) -> Self {
+        self.date.month = month;
+        self
+    }
+
+    /// Set the year.
+    pub const fn year(mut self, year: u16) -> Self {
+        self.date.year = year;
+        self
+    }
+
+    /// Set the date.
+    pub const fn date(mut self, date: Date) -> Self {
+        self.date = date;
+        self
+    }
+
+    /// Set the layout.
+    pub const fn layout(mut self, layout: DateLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Use the [`DateLayout::DayMonth`] layout.
+    pub const fn day_month(self) -> Self {
+        self.layout(DateLayout::DayMonth)
+    }
+
+    /// Use the [`DateLayout::MonthDay`] layout.
+    pub const fn month_day(self) -> Self {
+        self.layout(DateLayout::MonthDay)
+    }
+
+    /// Use the [`DateLayout::Year`] layout.
+    pub const fn year_only(self) -> Self {
+        self.layout(DateLayout::Year)
+    }
+
+    /// Use the [`DateLayout::DayMonthYear`] layout.
+    pub const fn day_month_year(self) -> Self {
+        self.layout(DateLayout::DayMonthYear)
+    }
+
+    /// Finish setting the date.
+    pub fn finish(
+        self,
+    ) -> DisplayOptions<
+        'd,
+        N,
+        T,
+        CLK,
+        DIO,
+        DELAY,
+        impl DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+        M,
+    >
+    where
+        I: DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+    {
+        self.options.iter(self.date.frame(self.layout).into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::{mappings::str_from_byte, mock::Noop, TM1637Builder};
+
+    use super::*;
+
+    fn strs(frame: Frame<6>) -> Vec<&'static str> {
+        frame.into_iter().map(str_from_byte).collect()
+    }
+
+    #[test]
+    fn layouts() {
+        let date = Date::new(2026, 3, 7);
+
+        assert_eq!(
+            strs(date.frame(DateLayout::DayMonth)),
+            vec!["0", "7.", "0", "3"]
+        );
+        assert_eq!(
+            strs(date.frame(DateLayout::MonthDay)),
+            vec!["0", "3.", "0", "7"]
+        );
+        assert_eq!(strs(date.frame(DateLayout::Year)), vec!["2", "0", "2", "6"]);
+        assert_eq!(
+            strs(date.frame(DateLayout::DayMonthYear)),
+            vec!["0", "7.", "0", "3.", "2", "6"]
+        );
+        assert_eq!(
+            strs(Date::new(905, 12, 31).frame(DateLayout::Year)),
+            vec!["0", "9", "0", "5"]
+        );
+    }
+
+    #[test]
+    fn invalid_date_is_displayed_as_dashes() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let (_, iter) = tm.options().date().day(32).month(1).finish().calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(vec!["-", "-", "-", "-"], collected);
+    }
+}
@@ -0,0 +1,24 @@
+/// Layout of the digits of a date.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DateLayout {
+    /// `DD.MM` on 4 digits.
+    #[default]
+    DayMonth,
+    /// `MM.DD` on 4 digits.
+    MonthDay,
+    /// `YYYY` on 4 digits.
+    Year,
+    /// `DD.MM.YY` on 6 digits.
+    DayMonthYear,
+}
+
+impl DateLayout {
+    /// Returns the number of digits used by the layout.
+    pub const fn digits(&self) -> usize {
+        match self {
+            DateLayout::DayMonthYear => 6,
+            _ => 4,
+        }
+    }
+}
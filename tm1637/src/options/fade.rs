@@ -0,0 +1,449 @@
+//! Brightness animation settings.
+
+use crate::{step::cycles, Brightness, TM1637};
+
+mod easing;
+
+pub use easing::Easing;
+
+/// High-level API for fading the brightness to a given level.
+///
+/// # Example
+///
+/// Fade in from [`Brightness::Off`] to [`Brightness::L7`] in one second.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, options::Easing, Brightness, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop)
+///     .brightness(Brightness::Off)
+///     .build_blocking::<4>();
+///
+/// tm.options().str("HELO").display().ok();
+///
+/// tm.fade_to(Brightness::L7, 1000)
+///     .easing(Easing::EaseOut)
+///     .finish()
+///     .run();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FadeOptions<'d, const N: usize, T, CLK, DIO, DELAY> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    brightness: Brightness,
+    duration_ms: u32,
+    easing: Easing,
+    resolution: u32,
+    pwm_ms: Option<u32>,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY> FadeOptions<'d, N, T, CLK, DIO, DELAY> {
+    /// Create a new [`FadeOptions`] instance.
+    ///
+    /// The animation fades from the current brightness level of the `device` to `brightness`.
+    pub const fn new(
+        device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+        brightness: Brightness,
+        duration_ms: u32,
+        easing: Easing,
+        resolution: u32,
+        pwm_ms: Option<u32>,
+    ) -> Self {
+        Self {
+            device,
+            brightness,
+            duration_ms,
+            easing,
+            resolution,
+            pwm_ms,
+        }
+    }
+
+    /// Create a new [`FadeOptions`] instance with default settings.
+    ///
+    /// Uses [`Easing::Linear`] with 16 steps and without software PWM.
+    pub const fn new_with_defaults(
+        device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+        brightness: Brightness,
+        duration_ms: u32,
+    ) -> Self {
+        Self::new(device, brightness, duration_ms, Easing::Linear, 16, None)
+    }
+
+    /// Set the easing curve.
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Set the number of steps the duration is divided into.
+    pub const fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Enable software PWM with the given period in milliseconds.
+    ///
+    /// Perceived levels between two hardware levels are approximated by toggling between them.
+    /// Between [`Brightness::Off`] and [`Brightness::L0`] this toggles the display on and off.
+    pub const fn pwm(mut self, period_ms: u32) -> Self {
+        self.pwm_ms = Some(period_ms);
+        self
+    }
+
+    /// Finish setting the fade animation.
+    pub fn finish(
+        self,
+    ) -> Fader<'d, N, T, CLK, DIO, DELAY, impl Iterator<Item = (Brightness, u32)>> {
+        let iter = ramp(
+            self.device.brightness().level(),
+            self.brightness.level(),
+            self.duration_ms,
+            self.resolution,
+            self.easing,
+            self.pwm_ms,
+        );
+
+        Fader::new(self.device, iter)
+    }
+}
+
+/// High-level API for breathing animations.
+///
+/// Each breath fades from the minimum to the maximum brightness level and back in one period.
+///
+/// # Example
+///
+/// Breathe 3 times with a period of 4 seconds.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, Brightness, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.options().str("HELO").display().ok();
+///
+/// tm.breathe(4000)
+///     .min(Brightness::L0)
+///     .max(Brightness::L5)
+///     .times(3)
+///     .finish()
+///     .run();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BreatheOptions<'d, const N: usize, T, CLK, DIO, DELAY> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    period_ms: u32,
+    min: Brightness,
+    max: Brightness,
+    count: Option<usize>,
+    easing: Easing,
+    resolution: u32,
+    pwm_ms: Option<u32>,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY> BreatheOptions<'d, N, T, CLK, DIO, DELAY> {
+    /// Create a new [`BreatheOptions`] instance.
+    ///
+    /// A `count` of [`None`] breathes forever.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+        period_ms: u32,
+        min: Brightness,
+        max: Brightness,
+        count: Option<usize>,
+        easing: Easing,
+        resolution: u32,
+        pwm_ms: Option<u32>,
+    ) -> Self {
+        Self {
+            device,
+            period_ms,
+            min,
+            max,
+            count,
+            easing,
+            resolution,
+            pwm_ms,
+        }
+    }
+
+    /// Create a new [`BreatheOptions`] instance with default settings.
+    ///
+    /// Breathes once between [`Brightness::Off`] and [`Brightness::L7`] using [`Easing::EaseInOut`] with 16 steps per half period and without software PWM.
+    pub const fn new_with_defaults(
+        device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+        period_ms: u32,
+    ) -> Self {
+        Self::new(
+            device,
+            period_ms,
+            Brightness::Off,
+            Brightness::L7,
+            Some(1),
+            Easing::EaseInOut,
+            16,
+            None,
+        )
+    }
+
+    /// Set the minimum brightness level.
+    pub const fn min(mut self, min: Brightness) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum brightness level.
+    pub const fn max(mut self, max: Brightness) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Breathe `count` times.
+    pub const fn times(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Breathe forever.
+    pub const fn forever(mut self) -> Self {
+        self.count = None;
+        self
+    }
+
+    /// Set the easing curve.
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Set the number of steps each half period is divided into.
+    pub const fn resolution(mut self, resolution: u32) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Enable software PWM with the given period in milliseconds.
+    ///
+    /// See [`FadeOptions::pwm`].
+    pub const fn pwm(mut self, period_ms: u32) -> Self {
+        self.pwm_ms = Some(period_ms);
+        self
+    }
+
+    /// Finish setting the breathing animation.
+    pub fn finish(
+        self,
+    ) -> Fader<'d, N, T, CLK, DIO, DELAY, impl Iterator<Item = (Brightness, u32)>> {
+        let (min, max) = (self.min.level(), self.max.level());
+        let (easing, resolution, pwm_ms) = (self.easing, self.resolution, self.pwm_ms);
+        let half_ms = self.period_ms / 2;
+        let rest_ms = self.period_ms - half_ms;
+
+        let iter = cycles(self.count).flat_map(move |_| {
+            ramp(min, max, half_ms, resolution, easing, pwm_ms)
+                .chain(ramp(max, min, rest_ms, resolution, easing, pwm_ms))
+        });
+
+        Fader::new(self.device, iter)
+    }
+}
+
+/// Brightness animation.
+///
+/// Responsible for running the animation.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Fader<'d, const N: usize, T, CLK, DIO, DELAY, I> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    iter: I,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, I> Fader<'d, N, T, CLK, DIO, DELAY, I> {
+    /// Create a new [`Fader`] instance.
+    ///
+    /// Each item of `iter` is a brightness level and the time in milliseconds to hold it.
+    pub const fn new(device: &'d mut TM1637<N, T, CLK, DIO, DELAY>, iter: I) -> Self {
+        Self { device, iter }
+    }
+}
+
+/// Returns the brightness levels and their durations to go from level `from` to level `to`.
+///
+/// Levels are indices in [`Brightness::all`].
+fn ramp(
+    from: u8,
+    to: u8,
+    duration_ms: u32,
+    resolution: u32,
+    easing: Easing,
+    pwm_ms: Option<u32>,
+) -> impl Iterator<Item = (Brightness, u32)> {
+    let resolution = resolution.max(1);
+
+    (1..=resolution).flat_map(move |i| {
+        let elapsed = |i: u32| (duration_ms as u64 * i as u64 / resolution as u64) as u32;
+        let slot_ms = elapsed(i) - elapsed(i - 1);
+
+        let progress = easing.apply(i * 1000 / resolution) as i32;
+        let milli_level = from as i32 * 1000 + (to as i32 - from as i32) * progress;
+
+        pwm(
+            (milli_level / 1000) as u8,
+            (milli_level % 1000) as u32,
+            slot_ms,
+            pwm_ms,
+        )
+    })
+}
+
+/// Returns the brightness levels to hold the perceived `level` + `fraction` (per mille) for `slot_ms` milliseconds.
+///
+/// Without software PWM, the fraction is rounded to the nearest hardware level.
+#[auto_enums::auto_enum(Iterator)]
+fn pwm(
+    level: u8,
+    fraction: u32,
+    slot_ms: u32,
+    pwm_ms: Option<u32>,
+) -> impl Iterator<Item = (Brightness, u32)> {
+    match pwm_ms {
+        Some(period_ms) if period_ms > 0 && fraction > 0 => {
+            let high_ms = period_ms * fraction / 1000;
+            let cycles = (slot_ms / period_ms).max(1) as usize;
+
+            ::core::iter::repeat_n(
+                [
+                    (Brightness::from_level(level + 1), high_ms),
+                    (Brightness::from_level(level), period_ms - high_ms),
+                ],
+                cycles,
+            )
+            .flatten()
+        }
+        _ => ::core::iter::once((
+            Brightness::from_level(level + (fraction >= 500) as u8),
+            slot_ms,
+        )),
+    }
+}
+
+#[::duplicate::duplicate_item(
+    module        async     await               Token                     DelayTrait                             ScrollIter            Signal                                              never;
+    [asynch]      [async]   [await.identity()]  [crate::tokens::Async]    [::embedded_hal_async::delay::DelayNs] [::futures::Stream]   [impl ::core::future::Future + Unpin + 'd]          [::core::future::pending::<()>()];
+    [blocking]    []        [identity()]        [crate::tokens::Blocking] [::embedded_hal::delay::DelayNs]       [Iterator]            [impl FnMut() -> bool + 'd]                         [|| false];
+)]
+mod module {
+    use ::embedded_hal::digital::OutputPin;
+    #[allow(unused_imports)]
+    use ::futures::StreamExt as _;
+
+    use crate::{step::Step, Brightness, ConditionalInputPin, Identity, TM1637};
+
+    use super::Fader;
+
+    impl<'d, const N: usize, CLK, DIO, DELAY, ERR, I> Fader<'d, N, Token, CLK, DIO, DELAY, I>
+    where
+        ERR: 'd,
+        CLK: OutputPin<Error = ERR>,
+        DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
+        DELAY: DelayTrait,
+        I: Iterator<Item = (Brightness, u32)> + 'd,
+    {
+        crate::runner::runner_methods!(
+            "brightness animation",
+            N,
+            [Token],
+            [async],
+            [await],
+            [ScrollIter],
+            [Signal],
+            [never],
+        );
+
+        /// Release the `device` and return the steps of the brightness animation with their delays.
+        #[allow(clippy::type_complexity)]
+        fn into_steps(
+            self,
+        ) -> (
+            &'d mut TM1637<N, Token, CLK, DIO, DELAY>,
+            impl Iterator<Item = (Step<impl Iterator<Item = u8>>, u32)>,
+        ) {
+            let steps = self.iter.map(|(brightness, delay_ms)| {
+                (
+                    Step::<::core::iter::Empty<u8>>::Brightness(brightness),
+                    delay_ms,
+                )
+            });
+
+            (self.device, steps)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn ramp_linear_without_pwm() {
+        let steps = ramp(0, 8, 800, 8, Easing::Linear, None).collect::<Vec<_>>();
+
+        assert_eq!(
+            steps,
+            vec![
+                (Brightness::L0, 100),
+                (Brightness::L1, 100),
+                (Brightness::L2, 100),
+                (Brightness::L3, 100),
+                (Brightness::L4, 100),
+                (Brightness::L5, 100),
+                (Brightness::L6, 100),
+                (Brightness::L7, 100),
+            ]
+        );
+
+        let steps = ramp(8, 6, 100, 2, Easing::Linear, None).collect::<Vec<_>>();
+
+        assert_eq!(steps, vec![(Brightness::L6, 50), (Brightness::L5, 50)]);
+    }
+
+    #[test]
+    fn ramp_with_pwm_toggles_between_levels() {
+        // Half way between L0 and L1 after the first step, then exactly L1.
+        let steps = ramp(1, 2, 40, 2, Easing::Linear, Some(10)).collect::<Vec<_>>();
+
+        assert_eq!(
+            steps,
+            vec![
+                (Brightness::L1, 5),
+                (Brightness::L0, 5),
+                (Brightness::L1, 5),
+                (Brightness::L0, 5),
+                (Brightness::L1, 20),
+            ]
+        );
+    }
+
+    #[test]
+    fn easing_keeps_end_points() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0), 0);
+            assert_eq!(easing.apply(1000), 1000);
+        }
+
+        assert!(Easing::EaseIn.apply(250) < 250);
+        assert!(Easing::EaseOut.apply(250) > 250);
+    }
+}
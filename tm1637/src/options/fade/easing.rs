@@ -0,0 +1,36 @@
+/// Easing curve for brightness animations.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// Start slow and speed up.
+    EaseIn,
+    /// Start fast and slow down.
+    EaseOut,
+    /// Start slow, speed up in the middle and slow down at the end.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Apply the easing curve to the `progress` given in per mille (`0..=1000`).
+    ///
+    /// Returns the eased progress in per mille.
+    pub const fn apply(&self, progress: u32) -> u32 {
+        let t = if progress > 1000 { 1000 } else { progress };
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t / 1000,
+            Easing::EaseOut => 1000 - (1000 - t) * (1000 - t) / 1000,
+            Easing::EaseInOut => {
+                if t < 500 {
+                    2 * t * t / 1000
+                } else {
+                    1000 - 2 * (1000 - t) * (1000 - t) / 1000
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Measurement settings.
+
+use crate::{
+    frame::Frame,
+    mappings::{DigitBits, SegmentBits},
+};
+
+mod precedence;
+mod unit;
+
+use super::DisplayOptions;
+pub use precedence::Precedence;
+pub use unit::Unit;
+
+/// High-level API for displaying a value with its unit.
+///
+/// The value is aligned to the right, followed by the suffix of the unit.
+/// If the value does not fit, decimals are dropped and the suffix is shortened or removed depending on the [`Precedence`].
+/// Values that do not fit at all are displayed as dashes.
+///
+/// # Example
+///
+/// Display a temperature of `23.46°C` in Fahrenheit.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, options::Unit, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<6>();
+///
+/// tm.options()
+///     .measurement(23.46, Unit::Celsius)
+///     .convert(Unit::Fahrenheit)
+///     .decimals(1)
+///     .finish()
+///     .display()
+///     .ok();
+/// ```
+///
+/// The display will show:
+///
+/// ```text
+/// +---+ +---+ +---+ +---+ +---+ +---+
+/// |   | | 7 | | 4.| | 2 | | ° | | F |
+/// +---+ +---+ +---+ +---+ +---+ +---+
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MeasurementDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, M> {
+    options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+    value: f32,
+    unit: Unit,
+    decimals: u8,
+    precedence: Precedence,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
+    MeasurementDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>
+{
+    /// Create a new [`MeasurementDisplayOptions`] instance with default settings.
+    ///
+    /// Displays one decimal and keeps the suffix.
+    pub const fn new_with_defaults(
+        options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+        value: f32,
+        unit: Unit,
+    ) -> Self {
+        Self {
+            options,
+            value,
+            unit,
+            decimals: 1,
+            precedence: Precedence::Suffix,
+        }
+    }
+
+    /// Convert the value to the given unit.
+    ///
+    /// Only temperatures are converted, other units are replaced without converting the value.
+    pub fn convert(mut self, unit: Unit) -> Self {
+        self.value = self.unit.convert(self.value, unit);
+        self.unit = unit;
+        self
+    }
+
+    /// Set the maximum number of decimals.
+    pub const fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Set what to keep when the value does not fit.
+    pub const fn precedence(mut self, precedence: Precedence) -> Self {
+        self.precedence = precedence;
+        self
+    }
+
+    /// Finish setting the measurement.
+    pub fn finish(
+        self,
+    ) -> DisplayOptions<
+        'd,
+        N,
+        T,
+        CLK,
+        DIO,
+        DELAY,
+        impl DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+        M,
+    >
+    where
+        I: DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+    {
+        let frame = measurement::<N>(self.value, self.unit, self.decimals, self.precedence);
+
+        self.options.iter(frame.into_iter())
+    }
+}
+
+/// Formats the `value` followed by the suffix of the `unit` on `N` digits.
+fn measurement<const N: usize>(
+    value: f32,
+    unit: Unit,
+    decimals: u8,
+    precedence: Precedence,
+) -> Frame<N> {
+    let suffixes = [unit.suffix(), unit.short_suffix(), &[]];
+    let decimals = (0..=decimals).rev();
+
+    let mut candidates = suffixes
+        .into_iter()
+        .flat_map(|suffix| decimals.clone().map(move |decimals| (suffix, decimals)));
+
+    let mut by_digits = decimals
+        .clone()
+        .flat_map(|decimals| suffixes.into_iter().map(move |suffix| (suffix, decimals)));
+
+    let fitting = match precedence {
+        Precedence::Suffix => candidates.find_map(|(s, d)| fit::<N>(value, d, s)),
+        Precedence::Digits => by_digits.find_map(|(s, d)| fit::<N>(value, d, s)),
+    };
+
+    fitting.unwrap_or_else(|| Frame::new(::core::iter::repeat_n(SegmentBits::SegG as u8, N)))
+}
+
+/// Formats the `value` with `decimals` right aligned followed by the `suffix`, if it fits on `N` digits.
+fn fit<const N: usize>(value: f32, decimals: u8, suffix: &[u8]) -> Option<Frame<N>> {
+    let width = N.checked_sub(suffix.len())?;
+    let scale = (0..decimals).fold(1.0_f32, |scale, _| scale * 10.0);
+
+    let scaled = (value * scale + if value.is_sign_negative() { -0.5 } else { 0.5 }) as i64;
+    let negative = scaled < 0;
+    let mut rest = scaled.unsigned_abs();
+
+    let mut len = 1;
+    while rest / 10_u64.pow(len) > 0 {
+        len += 1;
+    }
+
+    let len = (len as usize).max(decimals as usize + 1);
+
+    if len + negative as usize > width {
+        return None;
+    }
+
+    let mut bytes = [0; N];
+
+    for i in 0..len {
+        let byte = &mut bytes[width - 1 - i];
+
+        *byte = DigitBits::from_digit((rest % 10) as u8) as u8;
+
+        if decimals > 0 && i == decimals as usize {
+            *byte |= SegmentBits::Dot as u8;
+        }
+
+        rest /= 10;
+    }
+
+    if negative {
+        bytes[width - 1 - len] = SegmentBits::SegG as u8;
+    }
+
+    bytes[width..].copy_from_slice(suffix);
+
+    Some(Frame::new(bytes.into_iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::mappings::str_from_byte;
+
+    use super::*;
+
+    fn strs<const N: usize>(frame: Frame<N>) -> Vec<&'static str> {
+        frame.into_iter().map(str_from_byte).collect()
+    }
+
+    #[test]
+    fn drops_decimals_before_the_suffix() {
+        let frame = measurement::<4>(23.46, Unit::Celsius, 1, Precedence::Suffix);
+
+        assert_eq!(strs(frame)[..2], ["2", "3"]);
+
+        let frame = measurement::<6>(23.46, Unit::Celsius, 2, Precedence::Suffix);
+
+        assert_eq!(strs(frame)[..4], ["2", "3.", "4", "6"]);
+    }
+
+    #[test]
+    fn shortens_the_suffix_before_the_decimals() {
+        let frame = measurement::<4>(23.46, Unit::Celsius, 1, Precedence::Digits);
+
+        assert_eq!(strs(frame), vec!["2", "3.", "5", "C"]);
+    }
+
+    #[test]
+    fn negatives_conversion_and_overflow() {
+        let frame = measurement::<4>(-5.0, Unit::Volt, 1, Precedence::Suffix);
+
+        assert_eq!(strs(frame), vec!["-", "5.", "0", "U"]);
+
+        let fahrenheit = Unit::Celsius.convert(100.0, Unit::Fahrenheit);
+        let frame = measurement::<6>(fahrenheit, Unit::Fahrenheit, 0, Precedence::Suffix);
+
+        assert_eq!(strs(frame)[1..4], ["2", "1", "2"]);
+
+        let frame = measurement::<4>(-12345.0, Unit::Hertz, 0, Precedence::Suffix);
+
+        assert_eq!(strs(frame), vec!["-", "-", "-", "-"]);
+    }
+}
@@ -0,0 +1,10 @@
+/// What to keep when a measurement does not fit on the display.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Precedence {
+    /// Drop decimals before shortening the suffix.
+    #[default]
+    Suffix,
+    /// Shorten the suffix before dropping decimals.
+    Digits,
+}
@@ -0,0 +1,67 @@
+use crate::mappings::{LoCharBits, SegmentBits, UpCharBits};
+
+/// Degree symbol (°).
+const DEGREE: u8 = SegmentBits::SegA as u8
+    | SegmentBits::SegB as u8
+    | SegmentBits::SegF as u8
+    | SegmentBits::SegG as u8;
+
+/// Unit of a measurement.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Unit {
+    /// No unit.
+    #[default]
+    None,
+    /// Degrees Celsius, displayed as `°C` or `C`.
+    Celsius,
+    /// Degrees Fahrenheit, displayed as `°F` or `F`.
+    Fahrenheit,
+    /// Degrees, displayed as `°`.
+    Degrees,
+    /// Relative humidity in percent, displayed as `rH` or `H`.
+    Humidity,
+    /// Volts, displayed as `U`.
+    Volt,
+    /// Amperes, displayed as `A`.
+    Ampere,
+    /// Hertz, displayed as `Hz` or `H`.
+    Hertz,
+}
+
+impl Unit {
+    /// Returns the glyphs of the full suffix.
+    pub(crate) const fn suffix(&self) -> &'static [u8] {
+        match self {
+            Unit::None => &[],
+            Unit::Celsius => &[DEGREE, UpCharBits::UpC as u8],
+            Unit::Fahrenheit => &[DEGREE, UpCharBits::UpF as u8],
+            Unit::Degrees => &[DEGREE],
+            Unit::Humidity => &[LoCharBits::LoR as u8, UpCharBits::UpH as u8],
+            Unit::Volt => &[UpCharBits::UpU as u8],
+            Unit::Ampere => &[UpCharBits::UpA as u8],
+            Unit::Hertz => &[UpCharBits::UpH as u8, UpCharBits::UpZ as u8],
+        }
+    }
+
+    /// Returns the glyphs of the short suffix used when the value does not fit next to the full suffix.
+    pub(crate) const fn short_suffix(&self) -> &'static [u8] {
+        match self {
+            Unit::Celsius => &[UpCharBits::UpC as u8],
+            Unit::Fahrenheit => &[UpCharBits::UpF as u8],
+            Unit::Humidity | Unit::Hertz => &[UpCharBits::UpH as u8],
+            _ => self.suffix(),
+        }
+    }
+
+    /// Convert the `value` from this unit to the `to` unit.
+    ///
+    /// Only temperatures are converted, other values are returned as is.
+    pub(crate) fn convert(&self, value: f32, to: Unit) -> f32 {
+        match (self, to) {
+            (Unit::Celsius, Unit::Fahrenheit) => value * 9.0 / 5.0 + 32.0,
+            (Unit::Fahrenheit, Unit::Celsius) => (value - 32.0) * 5.0 / 9.0,
+            _ => value,
+        }
+    }
+}
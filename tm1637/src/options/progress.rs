@@ -0,0 +1,241 @@
+//! Progress indicator settings.
+
+use crate::{
+    mappings::SegmentBits,
+    numbers,
+    options::{
+        circles::{Spinner, SpinnerOptions},
+        BarDisplayOptions, DisplayOptions,
+    },
+    tokens::NotFlipped,
+    TM1637,
+};
+
+/// `%`-like glyph. The upper circle of the percent sign.
+const PERCENT: u8 = SegmentBits::SegA as u8
+    | SegmentBits::SegB as u8
+    | SegmentBits::SegF as u8
+    | SegmentBits::SegG as u8;
+
+/// High-level API for progress indicators.
+///
+/// The fraction is clamped between `0.0` and `1.0`.
+///
+/// # Example
+///
+/// Show the progress of a firmware update, then a spinner while it is verified.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.progress(0.0).percent().display().ok();
+///
+/// for chunk in 1..=64 {
+///     let previous = (chunk - 1) as f32 / 64.0;
+///
+///     tm.progress(chunk as f32 / 64.0).percent_from(previous).display().ok();
+/// }
+///
+/// tm.progress(1.0).indeterminate().repeat(3).finish().run();
+///
+/// tm.progress(0.5).bar().finish().display().ok();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ProgressOptions<'d, const N: usize, T, CLK, DIO, DELAY> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    fraction: f32,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY> ProgressOptions<'d, N, T, CLK, DIO, DELAY> {
+    /// Create a new [`ProgressOptions`] instance.
+    pub const fn new(device: &'d mut TM1637<N, T, CLK, DIO, DELAY>, fraction: f32) -> Self {
+        Self { device, fraction }
+    }
+
+    /// Returns the progress in per mille.
+    fn per_mille(&self) -> u32 {
+        per_mille(self.fraction)
+    }
+
+    /// Display the progress as a percentage followed by a `%`-like glyph, e.g. ` 42°`.
+    ///
+    /// The bytes are aligned to the right of the display.
+    ///
+    /// See [`ProgressOptions::percent_from`] to update a displayed percentage.
+    pub fn percent(
+        self,
+    ) -> DisplayOptions<
+        'd,
+        N,
+        T,
+        CLK,
+        DIO,
+        DELAY,
+        impl DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+        NotFlipped,
+    > {
+        DisplayOptions::empty(self.device)
+            .position(N.saturating_sub(4))
+            .iter(percent(self.fraction).into_iter())
+    }
+
+    /// Display the progress like [`ProgressOptions::percent`] while the `previous` progress is displayed.
+    ///
+    /// Only the digits that changed since the `previous` progress are written, nothing is written if the percentage did not change.
+    pub fn percent_from(
+        self,
+        previous: f32,
+    ) -> DisplayOptions<
+        'd,
+        N,
+        T,
+        CLK,
+        DIO,
+        DELAY,
+        impl DoubleEndedIterator<Item = u8> + ExactSizeIterator,
+        NotFlipped,
+    > {
+        let bytes = percent(self.fraction);
+        let previous = percent(previous);
+
+        let changed = |digit: &usize| bytes[*digit] != previous[*digit];
+        let first = (0..4).find(changed).unwrap_or(4);
+        let last = (0..4).rev().find(changed).map_or(first, |last| last + 1);
+
+        DisplayOptions::empty(self.device)
+            .position(N.saturating_sub(4) + first)
+            .iter(bytes.into_iter().skip(first).take(last - first))
+    }
+
+    /// Display the progress as a segment bar across all digits.
+    ///
+    /// See [`BarDisplayOptions`].
+    pub fn bar(
+        self,
+    ) -> BarDisplayOptions<'d, N, T, CLK, DIO, DELAY, ::core::iter::Empty<u8>, NotFlipped> {
+        let per_mille = self.per_mille();
+
+        DisplayOptions::empty(self.device).bar(per_mille, 1000)
+    }
+
+    /// Ignore the fraction and run a bouncing bar while the progress is unknown.
+    ///
+    /// See [`SpinnerOptions`].
+    pub const fn indeterminate(self) -> SpinnerOptions<'d, N, T, CLK, DIO, DELAY, NotFlipped> {
+        SpinnerOptions::new_with_defaults(self.device, Spinner::Bounce, NotFlipped)
+    }
+}
+
+/// Returns the `fraction` of `scale` rounded to the nearest integer.
+fn scaled(fraction: f32, scale: f32) -> u32 {
+    match fraction.is_nan() {
+        true => 0,
+        false => (fraction.clamp(0.0, 1.0) * scale + 0.5) as u32,
+    }
+}
+
+/// Returns the `fraction` in per mille.
+fn per_mille(fraction: f32) -> u32 {
+    scaled(fraction, 1000.0)
+}
+
+/// Returns the bytes of the `fraction` as a percentage followed by a `%`-like glyph.
+fn percent(fraction: f32) -> [u8; 4] {
+    let percent = scaled(fraction, 100.0);
+
+    let [hundreds, tens, ones] = match percent {
+        100 => numbers::u16_3(100),
+        _ => {
+            let [tens, ones] = numbers::r_u8_2(percent as u8);
+
+            [0, tens, ones]
+        }
+    };
+
+    [hundreds, tens, ones, PERCENT]
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::{mappings::str_from_byte, mock::Noop, TM1637Builder};
+
+    use super::*;
+
+    #[test]
+    fn percent() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let (_, iter) = tm.progress(0.424).percent().calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(collected[..3], [" ", "4", "2"]);
+
+        // Rounded once, not to per mille first.
+        let (_, iter) = tm.progress(0.4249).percent().calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(collected[..3], [" ", "4", "2"]);
+
+        let (_, iter) = tm.progress(2.0).percent().calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(collected[..3], ["1", "0", "0"]);
+
+        let (_, iter) = tm.progress(f32::NAN).percent().calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(collected[..3], [" ", " ", "0"]);
+    }
+
+    #[test]
+    fn percent_from_writes_only_changed_digits() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let (position, iter) = tm.progress(0.43).percent_from(0.42).calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(position, 2);
+        assert_eq!(collected, ["3"]);
+
+        let (position, iter) = tm.progress(0.52).percent_from(0.42).calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(position, 1);
+        assert_eq!(collected, ["5"]);
+
+        let (position, iter) = tm.progress(1.0).percent_from(0.99).calculate();
+        let collected = iter.map(str_from_byte).collect::<Vec<_>>();
+
+        assert_eq!(position, 0);
+        assert_eq!(collected, ["1", "0", "0"]);
+
+        let (_, iter) = tm.progress(0.421).percent_from(0.42).calculate();
+
+        assert_eq!(iter.count(), 0);
+
+        let (_, iter) = tm.progress(0.4249).percent_from(0.42).calculate();
+
+        assert_eq!(iter.count(), 0);
+    }
+
+    #[test]
+    fn bar_fills_proportionally() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let (_, iter) = tm.progress(0.5).bar().finish().calculate();
+
+        let full = SegmentBits::SegB as u8
+            | SegmentBits::SegC as u8
+            | SegmentBits::SegE as u8
+            | SegmentBits::SegF as u8;
+
+        assert_eq!(iter.collect::<Vec<_>>(), vec![full, full, 0, 0]);
+    }
+}
@@ -1,3 +1,8 @@
+use crate::{
+    frame::{Frame, BUFFER_CAPACITY},
+    step::cycles_of,
+};
+
 use super::{scroll::Scroller, DisplayOptions};
 
 /// High-level API for repeat animations.
@@ -44,6 +49,7 @@ use super::{scroll::Scroller, DisplayOptions};
 pub struct RepeatDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, D> {
     options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, D>,
     delay_ms: u32,
+    count: Option<usize>,
 }
 
 impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
@@ -54,7 +60,11 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
         options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
         delay_ms: u32,
     ) -> Self {
-        Self { options, delay_ms }
+        Self {
+            options,
+            delay_ms,
+            count: Some(1),
+        }
     }
 
     /// Create a new [`RepeatDisplayOptions`] instance with default settings.
@@ -70,6 +80,22 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
         self
     }
 
+    /// Repeat the animation `count` times.
+    ///
+    /// The bytes are buffered to be replayed, only the first 64 bytes are displayed.
+    pub const fn repeat(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Repeat the animation forever.
+    ///
+    /// See [`RepeatDisplayOptions::repeat`].
+    pub const fn forever(mut self) -> Self {
+        self.count = None;
+        self
+    }
+
     /// Finish setting the repeat animation.
     pub fn finish(
         self,
@@ -86,7 +112,7 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
     where
         I: DoubleEndedIterator<Item = u8> + ExactSizeIterator,
     {
-        let iter = self.options.iter.map(move |i| [i]).map(|i| i.into_iter());
+        let iter = repeated(self.options.iter, self.count).map(|i| [i].into_iter());
 
         Scroller::new(
             self.options.device,
@@ -98,3 +124,18 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
         )
     }
 }
+
+/// Returns the bytes repeated `count` times or forever if `count` is [`None`].
+///
+/// The bytes are only buffered if they are repeated. Empty bytes are not repeated forever.
+#[auto_enums::auto_enum(Iterator)]
+fn repeated(bytes: impl Iterator<Item = u8>, count: Option<usize>) -> impl Iterator<Item = u8> {
+    match count {
+        Some(1) => bytes,
+        _ => {
+            let bytes = Frame::<BUFFER_CAPACITY>::new(bytes);
+
+            cycles_of(count, bytes.len() == 0).flat_map(move |_| bytes)
+        }
+    }
+}
@@ -1,13 +1,22 @@
 //! Scroll animation settings.
 
-use crate::TM1637;
+use crate::{
+    frame::{Frame, BUFFER_CAPACITY},
+    step::cycles_of,
+    TM1637,
+};
 
 mod direction;
 mod style;
+mod timing;
 
-use super::{windows::windows, DisplayOptions};
+use super::{
+    windows::{windows, windows_len},
+    DisplayOptions,
+};
 pub use direction::ScrollDirection;
 pub use style::ScrollStyle;
+pub(crate) use timing::ScrollTiming;
 
 /// High-level API for scroll animations.
 #[derive(Debug)]
@@ -15,8 +24,13 @@ pub use style::ScrollStyle;
 pub struct ScrollDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, D> {
     options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, D>,
     delay_ms: u32,
+    delays_ms: &'static [u32],
     direction: ScrollDirection,
     style: ScrollStyle,
+    static_fit: bool,
+    pause_start_ms: u32,
+    pause_end_ms: u32,
+    count: Option<usize>,
 }
 
 impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
@@ -32,8 +46,13 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
         Self {
             options,
             delay_ms,
+            delays_ms: &[],
             direction,
             style,
+            static_fit: true,
+            pause_start_ms: 0,
+            pause_end_ms: 0,
+            count: Some(1),
         }
     }
 
@@ -55,6 +74,15 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
         self
     }
 
+    /// Set the delays in milliseconds of the first steps of each pass, e.g. to hold the first step longer.
+    ///
+    /// The `i`-th step waits `delays_ms[i]`, steps past the end of `delays_ms` wait [`ScrollDisplayOptions::delay_ms`].
+    /// The pauses are added on top.
+    pub const fn delays_ms(mut self, delays_ms: &'static [u32]) -> Self {
+        self.delays_ms = delays_ms;
+        self
+    }
+
     /// Set the animation direction.
     pub const fn direction(mut self, direction: ScrollDirection) -> Self {
         self.direction = direction;
@@ -91,6 +119,59 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
         self
     }
 
+    /// Set the animation style to [`ScrollStyle::Bounce`].
+    ///
+    /// Only the first 64 bytes are scrolled, longer input is truncated.
+    pub const fn bounce(mut self) -> Self {
+        self.style = ScrollStyle::Bounce;
+        self
+    }
+
+    /// Show bytes that are not wider than the display statically (`true`, the default) or scroll them anyway (`false`).
+    ///
+    /// Bytes that are scrolled anyway are padded with blanks to one byte wider than the display.
+    pub const fn static_fit(mut self, static_fit: bool) -> Self {
+        self.static_fit = static_fit;
+        self
+    }
+
+    /// Hold the first step for additional `pause_ms` milliseconds.
+    pub const fn pause_start_ms(mut self, pause_ms: u32) -> Self {
+        self.pause_start_ms = pause_ms;
+        self
+    }
+
+    /// Hold the last step (or the turning step of [`ScrollStyle::Bounce`]) for additional `pause_ms` milliseconds.
+    pub const fn pause_end_ms(mut self, pause_ms: u32) -> Self {
+        self.pause_end_ms = pause_ms;
+        self
+    }
+
+    /// Hold the first and last steps for additional `pause_ms` milliseconds.
+    ///
+    /// See [`ScrollDisplayOptions::pause_start_ms`] and [`ScrollDisplayOptions::pause_end_ms`].
+    pub const fn pause_ms(mut self, pause_ms: u32) -> Self {
+        self.pause_start_ms = pause_ms;
+        self.pause_end_ms = pause_ms;
+        self
+    }
+
+    /// Repeat the animation `count` times.
+    ///
+    /// The bytes are buffered to be replayed, only the first 64 bytes are displayed.
+    pub const fn repeat(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Repeat the animation forever.
+    ///
+    /// See [`ScrollDisplayOptions::repeat`].
+    pub const fn forever(mut self) -> Self {
+        self.count = None;
+        self
+    }
+
     /// Finish setting the scroll animation.
     pub fn finish(
         self,
@@ -107,20 +188,85 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
     where
         I: DoubleEndedIterator<Item = u8> + ExactSizeIterator,
     {
-        let iter =
-            windows::<N>(self.options.iter, self.direction, self.style).map(|i| i.into_iter());
+        let pad = match self.static_fit {
+            true => 0,
+            false => (N + 1).saturating_sub(self.options.iter.len()),
+        };
+
+        let len = match self.count {
+            Some(1) => self.options.iter.len() + pad,
+            _ => (self.options.iter.len() + pad).min(BUFFER_CAPACITY),
+        };
+
+        let frames = windows_len::<N>(len, self.style);
+        let end = match self.style {
+            ScrollStyle::Bounce => frames / 2,
+            _ => frames - 1,
+        };
+
+        // Repeated bounce passes share the window they turn back to.
+        let frames = match (self.style, self.count) {
+            (ScrollStyle::Bounce, Some(1)) => frames,
+            (ScrollStyle::Bounce, _) => frames - 1,
+            _ => frames,
+        };
+
+        let bytes = self.options.iter.chain(::core::iter::repeat_n(0, pad));
+
+        let iter = repeated_windows::<N>(bytes, self.direction, self.style, self.count)
+            .map(|i| i.into_iter());
 
         Scroller {
             device: self.options.device,
             inner_iter_len: N,
             position: self.options.position,
-            delay_ms: self.delay_ms,
+            timing: ScrollTiming::new(
+                self.delay_ms,
+                self.pause_start_ms,
+                self.pause_end_ms,
+                frames,
+                end,
+            )
+            .with_delays_ms(self.delays_ms),
             iter,
             _flip: self.options._flip,
         }
     }
 }
 
+/// Returns the windows of size `N` from the input bytes repeated `count` times or forever if `count` is [`None`].
+///
+/// The bytes are only buffered if the windows are repeated. Empty bytes are not repeated forever.
+///
+/// A [`ScrollStyle::Bounce`] pass ends on its first window, so the passes after the first one skip it.
+#[auto_enums::auto_enum(Iterator)]
+fn repeated_windows<const N: usize>(
+    bytes: impl DoubleEndedIterator<Item = u8>,
+    direction: ScrollDirection,
+    style: ScrollStyle,
+    count: Option<usize>,
+) -> impl Iterator<Item = [u8; N]> {
+    match count {
+        Some(1) => windows::<N>(bytes, direction, style),
+        _ => {
+            let bytes = Frame::<BUFFER_CAPACITY>::new(bytes);
+            let skip = match style {
+                ScrollStyle::Bounce if windows_len::<N>(bytes.len(), style) > 1 => 1,
+                _ => 0,
+            };
+
+            cycles_of(count, bytes.len() == 0)
+                .enumerate()
+                .flat_map(move |(pass, _)| {
+                    windows::<N>(bytes.into_iter(), direction, style).skip(match pass {
+                        0 => 0,
+                        _ => skip,
+                    })
+                })
+        }
+    }
+}
+
 /// Scroll animation.
 ///
 /// Responsible for running the animation.
@@ -130,7 +276,7 @@ pub struct Scroller<'d, const N: usize, T, CLK, DIO, DELAY, I, M> {
     device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
     inner_iter_len: usize,
     position: usize,
-    delay_ms: u32,
+    timing: ScrollTiming,
     iter: I,
     _flip: M,
 }
@@ -149,17 +295,105 @@ impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M> Scroller<'d, N, T, CLK, DIO,
             device,
             inner_iter_len,
             position,
-            delay_ms,
+            timing: ScrollTiming::constant(delay_ms),
             iter,
             _flip,
         }
     }
+
+    /// Repeat the animation `count` times.
+    ///
+    /// The steps are replayed by cloning the iterator.
+    pub fn repeat(
+        self,
+        count: usize,
+    ) -> Scroller<'d, N, T, CLK, DIO, DELAY, impl Iterator<Item = I::Item> + Clone, M>
+    where
+        I: Iterator + Clone,
+    {
+        self.cycle(Some(count))
+    }
+
+    /// Repeat the animation forever.
+    ///
+    /// See [`Scroller::repeat`] and [`Scroller::until`].
+    pub fn forever(
+        self,
+    ) -> Scroller<'d, N, T, CLK, DIO, DELAY, impl Iterator<Item = I::Item> + Clone, M>
+    where
+        I: Iterator + Clone,
+    {
+        self.cycle(None)
+    }
+
+    /// Repeat the animation `count` times or forever if `count` is [`None`].
+    ///
+    /// An animation without steps is not repeated forever.
+    pub(crate) fn cycle(
+        self,
+        count: Option<usize>,
+    ) -> Scroller<'d, N, T, CLK, DIO, DELAY, impl Iterator<Item = I::Item> + Clone, M>
+    where
+        I: Iterator + Clone,
+    {
+        let iter = self.iter;
+        let count = cycles_of(count, iter.clone().next().is_none());
+
+        Scroller {
+            device: self.device,
+            inner_iter_len: self.inner_iter_len,
+            position: self.position,
+            timing: self.timing,
+            iter: count.flat_map(move |_| iter.clone()),
+            _flip: self._flip,
+        }
+    }
+
+    /// Stop the animation before the next step once `cancel` returns `true`.
+    ///
+    /// # Example
+    ///
+    /// Run a loading spinner until the background work is done.
+    ///
+    /// ```rust
+    /// use core::sync::atomic::{AtomicBool, Ordering};
+    /// use tm1637_embedded_hal::{mock::Noop, TM1637Builder};
+    ///
+    /// static DONE: AtomicBool = AtomicBool::new(false);
+    ///
+    /// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+    ///
+    /// # DONE.store(true, Ordering::Relaxed);
+    /// tm.circles()
+    ///     .rotating()
+    ///     .forever()
+    ///     .finish()
+    ///     .until(|| DONE.load(Ordering::Relaxed))
+    ///     .run();
+    /// ```
+    pub fn until<F>(
+        self,
+        mut cancel: F,
+    ) -> Scroller<'d, N, T, CLK, DIO, DELAY, impl Iterator<Item = I::Item>, M>
+    where
+        I: Iterator,
+        F: FnMut() -> bool,
+    {
+        Scroller {
+            device: self.device,
+            inner_iter_len: self.inner_iter_len,
+            position: self.position,
+            timing: self.timing,
+            iter: self.iter.take_while(move |_| !cancel()),
+            _flip: self._flip,
+        }
+    }
 }
 
 #[::duplicate::duplicate_item(
-    module        async     await               Token                     DelayTrait                             ScrollIter;
-    [asynch]      [async]   [await.identity()]  [crate::tokens::Async]    [::embedded_hal_async::delay::DelayNs] [::futures::Stream];
-    [blocking]    []        [identity()]        [crate::tokens::Blocking] [::embedded_hal::delay::DelayNs]       [Iterator];
+    module        async     await               Token                     DelayTrait                             ScrollIter            Signal                                              never;
+    [asynch]      [async]   [await.identity()]  [crate::tokens::Async]    [::embedded_hal_async::delay::DelayNs] [::futures::Stream]   [impl ::core::future::Future + Unpin + 'd]          [::core::future::pending::<()>()];
+    [blocking]    []        [identity()]        [crate::tokens::Blocking] [::embedded_hal::delay::DelayNs]       [Iterator]            [impl FnMut() -> bool + 'd]                         [|| false];
 )]
 mod module {
     use ::embedded_hal::digital::OutputPin;
@@ -169,7 +403,8 @@ mod module {
     use crate::{
         align::{Align, Aligned},
         maybe_flipped::MaybeFlipped,
-        ConditionalInputPin, Error, Identity,
+        step::Step,
+        ConditionalInputPin, Identity, TM1637,
     };
 
     use super::Scroller;
@@ -214,16 +449,260 @@ mod module {
             Self::_calculate(self.position, self.iter, self.inner_iter_len)
         }
 
-        /// Return the scroll animation as an iterator.
-        pub fn steps(self) -> impl ScrollIter<Item = Result<(), Error<ERR>>> + 'd {
+        crate::runner::runner_methods!(
+            "scroll animation",
+            NUM_POS,
+            [Token],
+            [async],
+            [await],
+            [ScrollIter],
+            [Signal],
+            [never],
+        );
+
+        /// Release the `device` and return the steps of the scroll animation with their delays.
+        #[allow(clippy::type_complexity)]
+        fn into_steps(
+            self,
+        ) -> (
+            &'d mut TM1637<NUM_POS, Token, CLK, DIO, DELAY>,
+            impl Iterator<Item = (Step<impl Iterator<Item = u8>>, u32)>,
+        ) {
             let (position, iter) = Self::_calculate(self.position, self.iter, self.inner_iter_len);
+            let timing = self.timing;
 
-            self.device.scroll(position, self.delay_ms, iter)
-        }
+            let steps = iter
+                .enumerate()
+                .map(move |(i, bytes)| (Step::Display(position, bytes), timing.delay_ms(i)));
 
-        /// Run the scroll animation and return the number of steps.
-        pub async fn run(self) -> usize {
-            self.steps().count().await
+            (self.device, steps)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ::core::future::Future;
+
+    use crate::{mock::Noop, tokens::NotFlipped, TM1637Builder};
+
+    use super::{repeated_windows, ScrollDirection, ScrollStyle, ScrollTiming, Scroller};
+
+    #[test]
+    fn repeated_bounce_does_not_repeat_the_turning_window() {
+        let mut windows = repeated_windows::<4>(
+            b"12345".iter().copied(),
+            ScrollDirection::RightToLeft,
+            ScrollStyle::Bounce,
+            Some(3),
+        );
+
+        assert_eq!(windows.next(), Some(*b"1234"));
+        assert_eq!(windows.next(), Some(*b"2345"));
+        assert_eq!(windows.next(), Some(*b"1234"));
+        assert_eq!(windows.next(), Some(*b"2345"));
+        assert_eq!(windows.next(), Some(*b"1234"));
+        assert_eq!(windows.next(), Some(*b"2345"));
+        assert_eq!(windows.next(), Some(*b"1234"));
+        assert_eq!(windows.next(), None);
+
+        // Each pass after the first one has 2 windows and starts on the second window.
+        let timing = ScrollTiming::new(100, 1000, 500, 2, 1);
+
+        assert_eq!(timing.delay_ms(0), 1100);
+        assert_eq!(timing.delay_ms(1), 600);
+        assert_eq!(timing.delay_ms(2), 1100);
+        assert_eq!(timing.delay_ms(3), 600);
+    }
+
+    #[test]
+    fn repeat_replays_the_windows() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let steps = tm
+            .options()
+            .str("123456")
+            .scroll()
+            .linear()
+            .repeat(3)
+            .finish()
+            .run();
+
+        assert_eq!(steps, 9);
+
+        let steps = tm.circles().rotating().finish().repeat(2).run();
+
+        assert_eq!(steps, 12);
+    }
+
+    #[test]
+    fn empty_bytes_are_not_repeated_forever() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let steps = tm.options().str("").scroll().forever().finish().run();
+
+        assert_eq!(steps, 0);
+
+        let steps = tm.options().str("").repeat().forever().finish().run();
+
+        assert_eq!(steps, 0);
+
+        let steps = Scroller::new(
+            &mut tm,
+            4,
+            0,
+            0,
+            ::core::iter::empty::<::core::array::IntoIter<u8, 4>>(),
+            NotFlipped,
+        )
+        .forever()
+        .run();
+
+        assert_eq!(steps, 0);
+    }
+
+    #[test]
+    fn until_cancels_an_endless_animation() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+        let mut remaining = 10;
+
+        let steps = tm
+            .options()
+            .str("123456")
+            .scroll()
+            .forever()
+            .finish()
+            .until(|| {
+                remaining -= 1;
+                remaining < 0
+            })
+            .run();
+
+        assert_eq!(steps, 10);
+    }
+
+    #[test]
+    fn run_until_stops_before_the_next_step() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+        let mut remaining = 2;
+
+        let steps = tm
+            .options()
+            .str("123456")
+            .scroll()
+            .forever()
+            .finish()
+            .run_until(|| {
+                remaining -= 1;
+                remaining < 0
+            });
+
+        assert_eq!(steps, 2);
+
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_async::<4>();
+
+        let run = async {
+            let finished = tm
+                .options()
+                .str("123456")
+                .scroll()
+                .linear()
+                .finish()
+                .run_until(::core::future::pending::<()>())
+                .await;
+
+            let interrupted = tm
+                .options()
+                .str("123456")
+                .scroll()
+                .forever()
+                .finish()
+                .run_until(::core::future::ready(()))
+                .await;
+
+            (finished, interrupted)
+        };
+
+        let mut run = ::core::pin::pin!(run);
+        let mut cx = ::core::task::Context::from_waker(::futures::task::noop_waker_ref());
+
+        let steps = loop {
+            if let ::core::task::Poll::Ready(steps) = run.as_mut().poll(&mut cx) {
+                break steps;
+            }
+        };
+
+        assert_eq!(steps, (3, 0));
+    }
+
+    #[test]
+    fn pauses_are_added_at_the_ends() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        // 3 windows forward, 2 windows back
+        let scroller = tm
+            .options()
+            .str("123456")
+            .scroll()
+            .bounce()
+            .delay_ms(100)
+            .pause_start_ms(1000)
+            .pause_end_ms(500)
+            .finish();
+
+        let delays = (0..6).map(|i| scroller.timing.delay_ms(i));
+
+        assert!(delays.eq([1100, 100, 600, 100, 100, 1100]));
+    }
+
+    #[test]
+    fn delays_override_the_first_steps() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let scroller = tm
+            .options()
+            .str("123456")
+            .scroll()
+            .linear()
+            .delay_ms(100)
+            .delays_ms(&[1000, 200])
+            .pause_end_ms(500)
+            .repeat(2)
+            .finish();
+
+        let delays = (0..6).map(|i| scroller.timing.delay_ms(i));
+
+        assert!(delays.eq([1000, 200, 600, 1000, 200, 600]));
+    }
+
+    #[test]
+    fn fitting_bytes_are_displayed_statically() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let steps = tm.options().str("12").scroll().bounce().finish().run();
+
+        assert_eq!(steps, 1);
+
+        let steps = tm
+            .options()
+            .str("12")
+            .scroll()
+            .bounce()
+            .static_fit(false)
+            .finish()
+            .run();
+
+        assert_eq!(steps, 3);
+
+        let steps = tm
+            .options()
+            .str("1234")
+            .scroll()
+            .circular()
+            .static_fit(false)
+            .finish()
+            .run();
+
+        assert_eq!(steps, 6);
+    }
+}
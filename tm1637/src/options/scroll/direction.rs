@@ -1,5 +1,5 @@
 /// Direction for scrolling bytes.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ScrollDirection {
     /// Move bytes from left to right.
@@ -1,6 +1,12 @@
 /// Style for scrolling bytes.
-#[derive(Debug, Default, Clone, Copy)]
+///
+/// Bytes that fit on the display are shown statically in all styles,
+/// see [`ScrollDisplayOptions::static_fit`](super::ScrollDisplayOptions::static_fit).
+///
+/// New styles may be added in minor releases, so matches must include a wildcard arm.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
 pub enum ScrollStyle {
     #[default]
     /// Bytes are moved in circular overlapping windows.
@@ -59,4 +65,35 @@ pub enum ScrollStyle {
     /// +---+ +---+ +---+ +---+
     /// ```
     Linear,
+    /// Bytes are moved in windows back and forth (ping-pong).
+    ///
+    /// The bytes are buffered to scroll back, only the first 64 bytes are displayed.
+    /// Longer input is silently truncated, the animation turns at the 64th byte.
+    ///
+    /// # Example
+    ///
+    /// The display has 4 digits and the bytes are `HELLO `, the display will show:
+    ///
+    /// ```text
+    /// +---+ +---+ +---+ +---+
+    /// | H | | E | | L | | L |
+    /// +---+ +---+ +---+ +---+
+    ///
+    /// +---+ +---+ +---+ +---+
+    /// | E | | L | | L | | O |
+    /// +---+ +---+ +---+ +---+
+    ///
+    /// +---+ +---+ +---+ +---+
+    /// | L | | L | | O | |   |
+    /// +---+ +---+ +---+ +---+
+    ///
+    /// +---+ +---+ +---+ +---+
+    /// | E | | L | | L | | O |
+    /// +---+ +---+ +---+ +---+
+    ///
+    /// +---+ +---+ +---+ +---+
+    /// | H | | E | | L | | L |
+    /// +---+ +---+ +---+ +---+
+    /// ```
+    Bounce,
 }
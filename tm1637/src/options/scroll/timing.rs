@@ -0,0 +1,68 @@
+/// Timing of the steps of a [`Scroller`](super::Scroller).
+///
+/// Every step waits its entry in `delays_ms` or `delay_ms` if it has none. The first step of each pass additionally waits `start_ms`
+/// and the step at the `end` of each pass additionally waits `end_ms`.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct ScrollTiming {
+    delay_ms: u32,
+    /// Per-step delays of one pass, overriding `delay_ms`.
+    delays_ms: &'static [u32],
+    start_ms: u32,
+    end_ms: u32,
+    /// Number of steps in one pass.
+    frames: usize,
+    /// Index of the last step before turning or restarting.
+    end: usize,
+}
+
+impl ScrollTiming {
+    /// Create a new [`ScrollTiming`] with the same delay for every step.
+    pub(crate) const fn constant(delay_ms: u32) -> Self {
+        Self::new(delay_ms, 0, 0, 1, 0)
+    }
+
+    /// Create a new [`ScrollTiming`] instance.
+    pub(crate) const fn new(
+        delay_ms: u32,
+        start_ms: u32,
+        end_ms: u32,
+        frames: usize,
+        end: usize,
+    ) -> Self {
+        Self {
+            delay_ms,
+            delays_ms: &[],
+            start_ms,
+            end_ms,
+            frames: if frames == 0 { 1 } else { frames },
+            end,
+        }
+    }
+
+    /// Override the delays of the first steps of each pass.
+    pub(crate) const fn with_delays_ms(mut self, delays_ms: &'static [u32]) -> Self {
+        self.delays_ms = delays_ms;
+        self
+    }
+
+    /// Returns the delay in milliseconds after the given `step`.
+    pub(crate) const fn delay_ms(&self, step: usize) -> u32 {
+        let step = step % self.frames;
+        let mut delay_ms = if step < self.delays_ms.len() {
+            self.delays_ms[step]
+        } else {
+            self.delay_ms
+        };
+
+        if step == 0 {
+            delay_ms += self.start_ms;
+        }
+
+        if step == self.end {
+            delay_ms += self.end_ms;
+        }
+
+        delay_ms
+    }
+}
@@ -0,0 +1,373 @@
+//! Countdown and stopwatch settings.
+
+use crate::{frame::Frame, step::Step, TM1637};
+
+mod countdown;
+mod stopwatch;
+
+use super::DisplayOptions;
+pub use countdown::{Countdown, CountdownEnd};
+pub use stopwatch::Stopwatch;
+
+/// A timer that can be driven by an external tick or by a [`TimerRunner`].
+pub trait Timer {
+    /// Advance the timer by `elapsed_ms` milliseconds if it is running.
+    fn tick(&mut self, elapsed_ms: u32);
+
+    /// Returns `true` if the timer has nothing left to display.
+    fn is_done(&self) -> bool;
+
+    /// Returns the bytes to display on a `4-digit display`.
+    fn bytes(&self) -> [u8; 4];
+}
+
+impl<T: Timer + ?Sized> Timer for &mut T {
+    fn tick(&mut self, elapsed_ms: u32) {
+        T::tick(self, elapsed_ms)
+    }
+
+    fn is_done(&self) -> bool {
+        T::is_done(self)
+    }
+
+    fn bytes(&self) -> [u8; 4] {
+        T::bytes(self)
+    }
+}
+
+/// High-level API for running a [`Timer`] using the device `DELAY`.
+///
+/// To drive the timer using an external tick, call [`Timer::tick`] and display [`Timer::bytes`] instead.
+///
+/// # Example
+///
+/// Count down from one minute using a millisecond clock.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, options::Countdown, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// # let mut now_ms = 0;
+/// tm.options()
+///     .timer(Countdown::new(60_000))
+///     .clock_ms(|| {
+///         // Read your monotonic clock here.
+///         # now_ms += 100;
+///         now_ms
+///     })
+///     .finish()
+///     .run();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimerDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, M, R, C = fn() -> u32> {
+    options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+    timer: R,
+    tick_ms: u32,
+    clock: Option<C>,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M, R>
+    TimerDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M, R>
+{
+    /// Create a new [`TimerDisplayOptions`] instance with default settings.
+    ///
+    /// Ticks every 100ms without a clock.
+    pub const fn new_with_defaults(
+        options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+        timer: R,
+    ) -> Self {
+        Self {
+            options,
+            timer,
+            tick_ms: 100,
+            clock: None,
+        }
+    }
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M, R, C>
+    TimerDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M, R, C>
+{
+    /// Set the interval in milliseconds between each update of the display.
+    ///
+    /// Without a clock, the timer is advanced by `tick_ms` on every update.
+    /// Writing a frame takes several milliseconds at the default bit delay, so the timer runs slow,
+    /// use [`TimerDisplayOptions::clock_ms`] to advance it by the real elapsed time.
+    pub const fn tick_ms(mut self, tick_ms: u32) -> Self {
+        self.tick_ms = tick_ms;
+        self
+    }
+
+    /// Advance the timer by the elapsed time of the given millisecond `clock`, e.g. a monotonic system timer.
+    ///
+    /// The clock is read on every update and may wrap around.
+    pub fn clock_ms<F: FnMut() -> u32>(
+        self,
+        clock: F,
+    ) -> TimerDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M, R, F> {
+        TimerDisplayOptions {
+            options: self.options,
+            timer: self.timer,
+            tick_ms: self.tick_ms,
+            clock: Some(clock),
+        }
+    }
+
+    /// Finish setting the timer.
+    pub fn finish(self) -> TimerRunner<'d, N, T, CLK, DIO, DELAY, M, R, C> {
+        TimerRunner {
+            device: self.options.device,
+            position: self.options.position,
+            ticks: Ticks {
+                timer: self.timer,
+                tick_ms: self.tick_ms,
+                clock: self.clock,
+                last_ms: 0,
+                started: false,
+                done: false,
+            },
+            _flip: self.options._flip,
+        }
+    }
+}
+
+/// Timer animation.
+///
+/// Responsible for running the timer until [`Timer::is_done`]. A [`Stopwatch`] never ends.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimerRunner<'d, const N: usize, T, CLK, DIO, DELAY, M, R, C = fn() -> u32> {
+    device: &'d mut TM1637<N, T, CLK, DIO, DELAY>,
+    position: usize,
+    ticks: Ticks<R, C>,
+    _flip: M,
+}
+
+/// Steps of a [`TimerRunner`] before flipping and aligning the frames.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Ticks<R, C> {
+    timer: R,
+    tick_ms: u32,
+    clock: Option<C>,
+    /// Clock reading of the previous update.
+    last_ms: u32,
+    started: bool,
+    done: bool,
+}
+
+impl<R: Timer, C: FnMut() -> u32> Iterator for Ticks<R, C> {
+    type Item = (Step<Frame<4>>, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let elapsed_ms = match self.clock.as_mut() {
+            Some(clock) => {
+                let now_ms = clock();
+                let elapsed_ms = now_ms.wrapping_sub(self.last_ms);
+
+                self.last_ms = now_ms;
+
+                elapsed_ms
+            }
+            None => self.tick_ms,
+        };
+
+        if self.started {
+            self.timer.tick(elapsed_ms);
+        }
+
+        self.started = true;
+        self.done = self.timer.is_done();
+
+        let delay_ms = if self.done { 0 } else { self.tick_ms };
+
+        Some((
+            Step::Display(0, Frame::new(self.timer.bytes().into_iter())),
+            delay_ms,
+        ))
+    }
+}
+
+#[::duplicate::duplicate_item(
+    module        async     await               Token                     DelayTrait                             ScrollIter            Signal                                              never;
+    [asynch]      [async]   [await.identity()]  [crate::tokens::Async]    [::embedded_hal_async::delay::DelayNs] [::futures::Stream]   [impl ::core::future::Future + Unpin + 'd]          [::core::future::pending::<()>()];
+    [blocking]    []        [identity()]        [crate::tokens::Blocking] [::embedded_hal::delay::DelayNs]       [Iterator]            [impl FnMut() -> bool + 'd]                         [|| false];
+)]
+mod module {
+    use ::embedded_hal::digital::OutputPin;
+    #[allow(unused_imports)]
+    use ::futures::StreamExt as _;
+
+    use crate::{
+        align::{Align, Aligned},
+        maybe_flipped::MaybeFlipped,
+        step::Step,
+        ConditionalInputPin, Identity, TM1637,
+    };
+
+    use super::{Timer, TimerRunner};
+
+    #[::duplicate::duplicate_item(
+        NUM_POS ;
+        [4] ;
+        [6] ;
+    )]
+    impl<'d, CLK, DIO, DELAY, ERR, M, R, C> TimerRunner<'d, NUM_POS, Token, CLK, DIO, DELAY, M, R, C>
+    where
+        ERR: 'd,
+        CLK: OutputPin<Error = ERR>,
+        DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
+        DELAY: DelayTrait,
+        M: MaybeFlipped<NUM_POS> + 'd,
+        R: Timer + 'd,
+        C: FnMut() -> u32 + 'd,
+    {
+        crate::runner::runner_methods!(
+            "timer",
+            NUM_POS,
+            [Token],
+            [async],
+            [await],
+            [ScrollIter],
+            [Signal],
+            [never],
+        );
+
+        /// Release the `device` and return the steps of the timer with their delays.
+        #[allow(clippy::type_complexity)]
+        fn into_steps(
+            self,
+        ) -> (
+            &'d mut TM1637<NUM_POS, Token, CLK, DIO, DELAY>,
+            impl Iterator<Item = (Step<impl Iterator<Item = u8>>, u32)>,
+        ) {
+            let position = self.position;
+
+            let steps = self.ticks.map(move |(step, delay_ms)| {
+                let step = step.map(|_, frame| {
+                    let (position, bytes) = M::calculate(position, frame.into_iter());
+
+                    Align::<NUM_POS>::align(position, bytes)
+                });
+
+                (step, delay_ms)
+            });
+
+            (self.device, steps)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::{mappings::str_from_byte, mock::Noop, TM1637Builder};
+
+    use super::*;
+
+    fn strs(bytes: [u8; 4]) -> Vec<&'static str> {
+        bytes.into_iter().map(str_from_byte).collect()
+    }
+
+    #[test]
+    fn countdown_switches_formats_and_flashes_the_end() {
+        let mut countdown = Countdown::new(6_000_000 + 500);
+
+        assert_eq!(strs(countdown.bytes()), vec![" ", "1.", "4", "0"]);
+
+        countdown.tick(1_500);
+
+        assert_eq!(strs(countdown.bytes()), vec!["9", "9.", "5", "9"]);
+
+        countdown.reset();
+        countdown.tick(6_000_000);
+
+        assert_eq!(strs(countdown.bytes()), vec![" ", "0.", "0", "1"]);
+
+        countdown.pause();
+        countdown.tick(1_000);
+
+        assert!(!countdown.is_finished());
+
+        countdown.resume();
+        countdown.tick(500);
+
+        assert_eq!(strs(countdown.bytes()), vec!["E", "n", "d", " "]);
+
+        countdown.tick(500);
+
+        assert_eq!(strs(countdown.bytes()), vec![" ", " ", " ", " "]);
+
+        countdown.tick(2_500);
+
+        assert!(countdown.is_done());
+        assert_eq!(strs(countdown.bytes()), vec!["E", "n", "d", " "]);
+    }
+
+    #[test]
+    fn stopwatch_switches_formats() {
+        let mut stopwatch = Stopwatch::new();
+
+        stopwatch.tick(5_230);
+
+        assert_eq!(strs(stopwatch.bytes()), vec![" ", "5.", "2", "3"]);
+
+        stopwatch.tick(60_000);
+
+        assert_eq!(strs(stopwatch.bytes()), vec![" ", "1.", "0", "5"]);
+
+        stopwatch.tick(u32::MAX);
+
+        assert_eq!(strs(stopwatch.bytes()), vec!["9", "9.", "5", "9"]);
+    }
+
+    #[test]
+    fn runner_ends_after_the_flash() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let mut countdown = Countdown::new(1_000).flash_ms(1_000);
+
+        let steps = tm
+            .options()
+            .timer(&mut countdown)
+            .tick_ms(100)
+            .finish()
+            .run();
+
+        assert_eq!(steps, 21);
+        assert!(countdown.is_done());
+    }
+
+    #[test]
+    fn runner_advances_by_the_clock() {
+        let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+
+        let mut stopwatch = Stopwatch::new();
+        let mut now_ms = u32::MAX - 100;
+
+        let steps = tm
+            .options()
+            .timer(&mut stopwatch)
+            .clock_ms(|| {
+                // Writing a frame and the delay take longer than a tick.
+                now_ms = now_ms.wrapping_add(250);
+                now_ms
+            })
+            .finish()
+            .steps()
+            .take(4)
+            .count();
+
+        assert_eq!(steps, 4);
+        assert_eq!(stopwatch.elapsed_ms(), 750);
+    }
+}
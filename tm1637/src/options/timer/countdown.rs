@@ -0,0 +1,149 @@
+use crate::{formatters::clock_to_4digits, str::StrParser};
+
+use super::Timer;
+
+/// What a [`Countdown`] displays once it reaches zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CountdownEnd {
+    /// Display `End`.
+    #[default]
+    Text,
+    /// Display `0:00`.
+    Zeros,
+}
+
+/// Countdown timer.
+///
+/// Displays `MM:SS` or `HH:MM` for durations of 100 minutes or more, rounding up to the next second.
+/// Once it reaches zero, the end is flashed for [`Countdown::flash_ms`] milliseconds and then displayed permanently.
+///
+/// # Example
+///
+/// Drive the countdown using an external tick.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, options::{Countdown, Timer}, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// let mut countdown = Countdown::new(90_000);
+///
+/// // Called every 100ms by your timer interrupt or executor.
+/// countdown.tick(100);
+///
+/// tm.options().slice(&countdown.bytes()).display().ok();
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Countdown {
+    duration_ms: u32,
+    elapsed_ms: u32,
+    running: bool,
+    end: CountdownEnd,
+    flash_ms: u32,
+}
+
+impl Countdown {
+    /// Create a new running [`Countdown`] of `duration_ms` milliseconds.
+    ///
+    /// Flashes `End` for 3 seconds.
+    pub const fn new(duration_ms: u32) -> Self {
+        Self {
+            duration_ms,
+            elapsed_ms: 0,
+            running: true,
+            end: CountdownEnd::Text,
+            flash_ms: 3000,
+        }
+    }
+
+    /// Set what is displayed once the countdown reaches zero.
+    pub const fn end(mut self, end: CountdownEnd) -> Self {
+        self.end = end;
+        self
+    }
+
+    /// Set for how long the end is flashed in milliseconds.
+    pub const fn flash_ms(mut self, flash_ms: u32) -> Self {
+        self.flash_ms = flash_ms;
+        self
+    }
+
+    /// Returns the remaining time in milliseconds.
+    pub const fn remaining_ms(&self) -> u32 {
+        self.duration_ms.saturating_sub(self.elapsed_ms)
+    }
+
+    /// Returns `true` if the countdown reached zero.
+    pub const fn is_finished(&self) -> bool {
+        self.elapsed_ms >= self.duration_ms
+    }
+
+    /// Returns `true` if the countdown is not paused.
+    pub const fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Pause the countdown.
+    pub const fn pause(&mut self) {
+        self.running = false;
+    }
+
+    /// Resume the countdown.
+    pub const fn resume(&mut self) {
+        self.running = true;
+    }
+
+    /// Restart the countdown from its full duration.
+    pub const fn reset(&mut self) {
+        self.elapsed_ms = 0;
+    }
+}
+
+impl Timer for Countdown {
+    fn tick(&mut self, elapsed_ms: u32) {
+        if self.running {
+            self.elapsed_ms = self.elapsed_ms.saturating_add(elapsed_ms);
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.elapsed_ms >= self.duration_ms.saturating_add(self.flash_ms)
+    }
+
+    fn bytes(&self) -> [u8; 4] {
+        if self.is_finished() {
+            let overrun_ms = self.elapsed_ms - self.duration_ms;
+
+            if overrun_ms < self.flash_ms && (overrun_ms / 500) % 2 == 1 {
+                return [0; 4];
+            }
+
+            return match self.end {
+                CountdownEnd::Text => {
+                    let mut bytes = [0; 4];
+
+                    bytes
+                        .iter_mut()
+                        .zip(StrParser::new("End"))
+                        .for_each(|(slot, byte)| *slot = byte);
+
+                    bytes
+                }
+                CountdownEnd::Zeros => clock_to_4digits(0, 0, true),
+            };
+        }
+
+        let seconds = self.remaining_ms().div_ceil(1000);
+
+        match seconds / 60 {
+            minutes @ 0..100 => clock_to_4digits(minutes as u8, (seconds % 60) as u8, true),
+            _ => clock_to_4digits(
+                (seconds / 3600).min(99) as u8,
+                (seconds / 60 % 60) as u8,
+                true,
+            ),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::formatters::clock_to_4digits;
+
+use super::Timer;
+
+/// Stopwatch.
+///
+/// Displays `SS.hh` for the first minute, then `MM:SS` and `HH:MM` from 100 minutes on.
+/// The display stops at `99:59` hours.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Stopwatch {
+    elapsed_ms: u32,
+    running: bool,
+}
+
+impl Stopwatch {
+    /// Create a new running [`Stopwatch`].
+    pub const fn new() -> Self {
+        Self {
+            elapsed_ms: 0,
+            running: true,
+        }
+    }
+
+    /// Returns the elapsed time in milliseconds.
+    pub const fn elapsed_ms(&self) -> u32 {
+        self.elapsed_ms
+    }
+
+    /// Returns `true` if the stopwatch is not paused.
+    pub const fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Pause the stopwatch.
+    pub const fn pause(&mut self) {
+        self.running = false;
+    }
+
+    /// Resume the stopwatch.
+    pub const fn resume(&mut self) {
+        self.running = true;
+    }
+
+    /// Reset the elapsed time to zero.
+    pub const fn reset(&mut self) {
+        self.elapsed_ms = 0;
+    }
+}
+
+impl Timer for Stopwatch {
+    fn tick(&mut self, elapsed_ms: u32) {
+        if self.running {
+            self.elapsed_ms = self.elapsed_ms.saturating_add(elapsed_ms);
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        false
+    }
+
+    fn bytes(&self) -> [u8; 4] {
+        let hundredths = self.elapsed_ms / 10;
+        let seconds = self.elapsed_ms / 1000;
+
+        match seconds / 60 {
+            0 => clock_to_4digits(seconds as u8, (hundredths % 100) as u8, true),
+            minutes @ 1..100 => clock_to_4digits(minutes as u8, (seconds % 60) as u8, true),
+            _ if seconds / 3600 < 100 => {
+                clock_to_4digits((seconds / 3600) as u8, (seconds / 60 % 60) as u8, true)
+            }
+            _ => clock_to_4digits(99, 59, true),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Transition animation settings.
+
+use crate::frame::Frame;
+
+mod effect;
+mod frames;
+
+use super::{scroll::Scroller, DisplayOptions};
+pub use effect::Effect;
+use frames::transition;
+
+/// High-level API for transitions between two frames.
+///
+/// The bytes of the [`DisplayOptions`] are the new frame.
+///
+/// # Example
+///
+/// Roll a counter from `0189` to `0190`.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, numbers, options::Effect, TM1637Builder};
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop).build_blocking::<4>();
+///
+/// tm.options()
+///     .u16_4(190)
+///     .transition_from(numbers::u16_4(189))
+///     .effect(Effect::Roll)
+///     .delay_ms(80)
+///     .finish()
+///     .run();
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TransitionDisplayOptions<'d, const N: usize, T, CLK, DIO, DELAY, I, M> {
+    options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+    from: Frame<N>,
+    effect: Effect,
+    delay_ms: u32,
+    seed: u32,
+}
+
+impl<'d, const N: usize, T, CLK, DIO, DELAY, I, M>
+    TransitionDisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>
+{
+    /// Create a new [`TransitionDisplayOptions`] instance with default settings.
+    ///
+    /// Uses [`Effect::Roll`] with a delay of 50ms between each step.
+    pub fn new_with_defaults(
+        options: DisplayOptions<'d, N, T, CLK, DIO, DELAY, I, M>,
+        from: impl IntoIterator<Item = u8>,
+    ) -> Self {
+        Self {
+            options,
+            from: Frame::new(from.into_iter()),
+            effect: Effect::Roll,
+            delay_ms: 50,
+            seed: 0,
+        }
+    }
+
+    /// Set the transition effect.
+    pub const fn effect(mut self, effect: Effect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// Set the delay in milliseconds between each animation step.
+    pub const fn delay_ms(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    /// Set the seed of the pseudo-random order used by [`Effect::Dissolve`].
+    pub const fn seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Finish setting the transition animation.
+    pub fn finish(
+        self,
+    ) -> Scroller<
+        'd,
+        N,
+        T,
+        CLK,
+        DIO,
+        DELAY,
+        impl Iterator<Item = impl DoubleEndedIterator<Item = u8> + ExactSizeIterator>,
+        M,
+    >
+    where
+        I: Iterator<Item = u8>,
+    {
+        let to = Frame::new(self.options.iter);
+        let len = self.from.len().max(to.len());
+
+        let iter = transition(self.from, to, self.effect, self.seed).map(Frame::into_iter);
+
+        Scroller::new(
+            self.options.device,
+            len,
+            self.options.position,
+            self.delay_ms,
+            iter,
+            self.options._flip,
+        )
+    }
+}
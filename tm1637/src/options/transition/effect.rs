@@ -0,0 +1,20 @@
+/// Effect for transitions between two frames.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Effect {
+    /// Roll each digit like a slot machine, passing through the intermediate digits.
+    ///
+    /// Bytes that are not digits are replaced on the first step.
+    #[default]
+    Roll,
+    /// Wipe the segments column by column from right to left.
+    WipeLeft,
+    /// Wipe the segments column by column from left to right.
+    WipeRight,
+    /// Turn the differing segments on and off one by one in a pseudo-random order.
+    Dissolve,
+    /// Shift the old frame up and out while the new frame comes in from the bottom.
+    ShiftUp,
+    /// Shift the old frame down and out while the new frame comes in from the top.
+    ShiftDown,
+}
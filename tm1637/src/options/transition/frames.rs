@@ -0,0 +1,267 @@
+use crate::{
+    frame::Frame,
+    mappings::{shift_down, shift_up, DigitBits, SegmentBits},
+};
+
+use super::Effect;
+
+/// Segments of each column of a digit from left to right.
+const COLUMNS: [u8; 3] = [
+    SegmentBits::SegF as u8 | SegmentBits::SegE as u8,
+    SegmentBits::SegA as u8 | SegmentBits::SegG as u8 | SegmentBits::SegD as u8,
+    SegmentBits::SegB as u8 | SegmentBits::SegC as u8 | SegmentBits::Dot as u8,
+];
+
+/// Returns the frames of the transition from `from` to `to` using the given `effect`.
+///
+/// Both frames are padded to the same length. The last frame is always `to`.
+#[auto_enums::auto_enum(Iterator)]
+pub(crate) fn transition<const N: usize>(
+    from: Frame<N>,
+    to: Frame<N>,
+    effect: Effect,
+    seed: u32,
+) -> impl Iterator<Item = Frame<N>> {
+    let len = from.len().max(to.len());
+    let (from, to) = (from.resize(len), to.resize(len));
+
+    match effect {
+        Effect::Roll => roll(from, to),
+        Effect::WipeLeft => wipe(from, to, false),
+        Effect::WipeRight => wipe(from, to, true),
+        Effect::Dissolve => Dissolve::new(from, to, seed),
+        Effect::ShiftUp => shift(from, to, shift_up, shift_down),
+        Effect::ShiftDown => shift(from, to, shift_down, shift_up),
+    }
+}
+
+/// Returns the digit represented by the `byte` ignoring the dot.
+fn digit(byte: u8) -> Option<u8> {
+    let byte = byte & !(SegmentBits::Dot as u8);
+
+    DigitBits::all_u8()
+        .iter()
+        .position(|digit| *digit == byte)
+        .map(|digit| digit as u8)
+}
+
+/// Number of roll steps for the digit at `index`.
+fn roll_steps<const N: usize>(from: &Frame<N>, to: &Frame<N>, index: usize) -> usize {
+    let (old, new) = (from.get(index), to.get(index));
+
+    match (digit(old), digit(new)) {
+        (Some(old), Some(new)) => ((new + 10 - old) % 10) as usize,
+        _ if old != new => 1,
+        _ => 0,
+    }
+}
+
+fn roll<const N: usize>(from: Frame<N>, to: Frame<N>) -> impl Iterator<Item = Frame<N>> {
+    let steps = (0..to.len())
+        .map(|i| roll_steps(&from, &to, i))
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    (1..=steps).map(move |step| {
+        to.map(|i, new| {
+            if step >= roll_steps(&from, &to, i) {
+                return new;
+            }
+
+            match digit(from.get(i)) {
+                Some(old) => {
+                    DigitBits::from_digit((old + step as u8) % 10) as u8
+                        | (new & SegmentBits::Dot as u8)
+                }
+                None => new,
+            }
+        })
+    })
+}
+
+fn wipe<const N: usize>(
+    from: Frame<N>,
+    to: Frame<N>,
+    to_right: bool,
+) -> impl Iterator<Item = Frame<N>> {
+    let columns = to.len() * COLUMNS.len();
+
+    (1..=columns.max(1)).map(move |step| {
+        to.map(|i, new| {
+            let mask = COLUMNS
+                .iter()
+                .enumerate()
+                .filter(|(c, _)| {
+                    let column = i * COLUMNS.len() + c;
+
+                    match to_right {
+                        true => column < step,
+                        false => column >= columns - step,
+                    }
+                })
+                .fold(0, |mask, (_, segments)| mask | segments);
+
+            (new & mask) | (from.get(i) & !mask)
+        })
+    })
+}
+
+/// Shifts the old frame out using `out` while the new frame is shifted in using `into`.
+fn shift<const N: usize>(
+    from: Frame<N>,
+    to: Frame<N>,
+    out: fn(u8) -> u8,
+    into: fn(u8) -> u8,
+) -> impl Iterator<Item = Frame<N>> {
+    let dot = SegmentBits::Dot as u8;
+    let half = move |i: usize, _| (out(from.get(i)) | into(into(to.get(i)))) & !dot;
+    let full = move |i: usize, _| (out(out(from.get(i))) | into(to.get(i))) & !dot;
+
+    [to.map(half), to.map(full), to].into_iter()
+}
+
+/// Dissolve iterator.
+///
+/// Toggles one differing segment per step in a pseudo-random order.
+#[derive(Debug)]
+struct Dissolve<const N: usize> {
+    current: Frame<N>,
+    to: Frame<N>,
+    state: u32,
+    started: bool,
+}
+
+impl<const N: usize> Dissolve<N> {
+    fn new(from: Frame<N>, to: Frame<N>, seed: u32) -> Self {
+        Self {
+            current: from,
+            to,
+            // xorshift gets stuck on zero
+            state: if seed == 0 { 0x9E37_79B9 } else { seed },
+            started: false,
+        }
+    }
+
+    fn random(&mut self) -> u32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        self.state
+    }
+}
+
+impl<const N: usize> Iterator for Dissolve<N> {
+    type Item = Frame<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.to.len();
+        let remaining = (0..len)
+            .map(|i| (self.current.get(i) ^ self.to.get(i)).count_ones())
+            .sum::<u32>();
+
+        if remaining == 0 {
+            if self.started {
+                return None;
+            }
+
+            self.started = true;
+
+            return Some(self.to);
+        }
+
+        self.started = true;
+
+        let mut pick = Some(self.random() % remaining);
+        let to = self.to;
+
+        self.current = self.current.map(|i, byte| {
+            let mut diff = byte ^ to.get(i);
+
+            while diff != 0 {
+                let bit = diff & diff.wrapping_neg();
+
+                match pick {
+                    Some(0) => {
+                        pick = None;
+
+                        return byte ^ bit;
+                    }
+                    Some(n) => pick = Some(n - 1),
+                    None => return byte,
+                }
+
+                diff &= !bit;
+            }
+
+            byte
+        });
+
+        Some(self.current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use crate::{mappings::str_from_byte, str::StrParser};
+
+    use super::*;
+
+    fn frame(str: &str) -> Frame<4> {
+        Frame::new(StrParser::new(str))
+    }
+
+    fn strs(frames: impl Iterator<Item = Frame<4>>) -> Vec<Vec<&'static str>> {
+        frames
+            .map(|frame| frame.into_iter().map(str_from_byte).collect())
+            .collect()
+    }
+
+    #[test]
+    fn roll_passes_through_intermediate_digits() {
+        let frames = strs(transition(frame("18"), frame("21"), Effect::Roll, 0));
+
+        assert_eq!(
+            frames,
+            vec![vec!["2", "9"], vec!["2", "0"], vec!["2", "1"],]
+        );
+    }
+
+    #[test]
+    fn wipe_right_replaces_columns_from_the_left() {
+        let frames = transition(frame("8"), frame(" "), Effect::WipeRight, 0).collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].get(0), DigitBits::Eight as u8 & !COLUMNS[0]);
+        assert_eq!(frames[1].get(0), COLUMNS[2] & !(SegmentBits::Dot as u8));
+        assert_eq!(frames[2].get(0), 0);
+    }
+
+    #[test]
+    fn dissolve_toggles_one_segment_per_step() {
+        let (from, to) = (frame("18"), frame("21"));
+        let differing = (0..2)
+            .map(|i| (from.get(i) ^ to.get(i)).count_ones())
+            .sum::<u32>();
+
+        let frames = transition(from, to, Effect::Dissolve, 42).collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), differing as usize);
+        assert_eq!(frames.last(), Some(&to));
+    }
+
+    #[test]
+    fn shift_ends_with_the_new_frame() {
+        let frames = transition(frame("8"), frame("8"), Effect::ShiftDown, 0).collect::<Vec<_>>();
+
+        assert_eq!(
+            frames[1].get(0),
+            SegmentBits::SegD as u8 | shift_up(DigitBits::Eight as u8)
+        );
+        assert_eq!(frames.last(), Some(&frame("8")));
+    }
+}
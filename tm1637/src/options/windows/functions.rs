@@ -1,4 +1,7 @@
-use crate::options::scroll::{ScrollDirection, ScrollStyle};
+use crate::{
+    frame::{Frame, BUFFER_CAPACITY},
+    options::scroll::{ScrollDirection, ScrollStyle},
+};
 
 use super::{CircularWindows, CircularWindowsReversed, LinearWindows};
 
@@ -12,6 +15,30 @@ pub fn windows<const N: usize>(
     match style {
         ScrollStyle::Circular => windows_circular::<N>(bytes, direction),
         ScrollStyle::Linear => windows_linear::<N>(bytes, direction),
+        ScrollStyle::Bounce => windows_bounce::<N>(bytes, direction),
+    }
+}
+
+/// Returns the number of windows of size `N` for `len` bytes using the given `style`.
+///
+/// See [`windows`].
+pub const fn windows_len<const N: usize>(len: usize, style: ScrollStyle) -> usize {
+    if len <= N {
+        return 1;
+    }
+
+    match style {
+        ScrollStyle::Circular => len + 1,
+        ScrollStyle::Linear => len - N + 1,
+        ScrollStyle::Bounce => {
+            let len = if len > BUFFER_CAPACITY {
+                BUFFER_CAPACITY
+            } else {
+                len
+            };
+
+            2 * (len - N) + 1
+        }
     }
 }
 
@@ -42,3 +69,83 @@ pub fn windows_linear<const N: usize>(
         ScrollDirection::LeftToRight => LinearWindows::<N, _>::new(bytes).rev(),
     }
 }
+
+/// Returns an iterator over bouncing windows of size `N` from the input bytes.
+///
+/// The bytes are buffered to scroll back, bytes beyond [`BUFFER_CAPACITY`] are ignored.
+///
+/// See [`windows`].
+#[auto_enums::auto_enum(Iterator)]
+pub fn windows_bounce<const N: usize>(
+    bytes: impl DoubleEndedIterator<Item = u8>,
+    direction: ScrollDirection,
+) -> impl Iterator<Item = [u8; N]> {
+    let bytes = Frame::<BUFFER_CAPACITY>::new(bytes);
+
+    match direction {
+        ScrollDirection::RightToLeft => LinearWindows::<N, _>::new(bytes.into_iter())
+            .chain(LinearWindows::<N, _>::new(bytes.into_iter()).rev().skip(1)),
+        ScrollDirection::LeftToRight => LinearWindows::<N, _>::new(bytes.into_iter())
+            .rev()
+            .chain(LinearWindows::<N, _>::new(bytes.into_iter()).skip(1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec;
+    use std::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn bounce_goes_back_and_forth() {
+        let collected =
+            windows_bounce::<4>(b"123456".iter().copied(), ScrollDirection::RightToLeft)
+                .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                [b'1', b'2', b'3', b'4'],
+                [b'2', b'3', b'4', b'5'],
+                [b'3', b'4', b'5', b'6'],
+                [b'2', b'3', b'4', b'5'],
+                [b'1', b'2', b'3', b'4'],
+            ],
+            collected
+        );
+
+        let collected = windows_bounce::<4>(b"12345".iter().copied(), ScrollDirection::LeftToRight)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                [b'2', b'3', b'4', b'5'],
+                [b'1', b'2', b'3', b'4'],
+                [b'2', b'3', b'4', b'5'],
+            ],
+            collected
+        );
+    }
+
+    #[test]
+    fn windows_len_matches_windows() {
+        let bytes = b"123456789";
+
+        for len in 0..bytes.len() {
+            for style in [
+                ScrollStyle::Circular,
+                ScrollStyle::Linear,
+                ScrollStyle::Bounce,
+            ] {
+                for direction in [ScrollDirection::LeftToRight, ScrollDirection::RightToLeft] {
+                    let count =
+                        windows::<4>(bytes[..len].iter().copied(), direction, style).count();
+
+                    assert_eq!(windows_len::<4>(len, style), count);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Orientation of the display.
+
+use crate::mappings::{flip, flip_mirror, mirror, SegmentBits};
+
+/// Orientation of the display.
+///
+/// Set using [`TM1637Builder::orientation`](crate::TM1637Builder::orientation) or [`TM1637::set_orientation`](crate::TM1637::set_orientation)
+/// and applied to every write to the display.
+///
+/// The compile-time [`DisplayOptions::flip`](crate::options::DisplayOptions::flip) is applied to the bytes before the orientation, so both stack.
+/// [`Orientation::Rotated`] combined with a flipped write cancels out and shows the bytes upright.
+///
+/// ## Dots
+///
+/// Dots are tied to the byte they were set on, see [`DisplayOptions::dot`](crate::options::DisplayOptions::dot).
+/// If the order of the digits is reversed, the physical dot of a digit appears before the digit.
+/// The dot is therefore moved to the digit following it, so `1.5` still reads as `1.5`.
+/// The dot of the last digit and dots moving to a digit that is not part of the same write are dropped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Orientation {
+    /// The display is mounted as intended.
+    #[default]
+    Normal,
+    /// The display is rotated by 180°.
+    ///
+    /// The digits are reversed and each digit is flipped and mirrored.
+    Rotated,
+    /// The display is mirrored horizontally, e.g. viewed behind a half-silvered mirror.
+    ///
+    /// The digits are reversed and each digit is mirrored.
+    Mirrored,
+    /// The display is flipped vertically, e.g. reflected by a heads-up display.
+    ///
+    /// The order of the digits is kept and each digit is flipped.
+    Flipped,
+}
+
+impl Orientation {
+    /// Returns `true` if the order of the digits is reversed.
+    pub const fn reverses(&self) -> bool {
+        matches!(self, Orientation::Rotated | Orientation::Mirrored)
+    }
+
+    /// Transform the segments of a single `byte`.
+    ///
+    /// The dot is kept.
+    pub const fn transform(&self, byte: u8) -> u8 {
+        match self {
+            Orientation::Normal => byte,
+            Orientation::Rotated => flip_mirror(byte),
+            Orientation::Mirrored => mirror(byte),
+            Orientation::Flipped => flip(byte),
+        }
+    }
+
+    /// Transforms the `digits` of a display with `N` positions, from left to right.
+    ///
+    /// Digits that are not written are [`None`].
+    pub(crate) fn apply<const N: usize>(&self, digits: [Option<u8>; N]) -> [Option<u8>; N] {
+        let mut transformed = [None; N];
+        let mut dot = 0;
+
+        for (index, byte) in digits.into_iter().enumerate() {
+            let Some(byte) = byte else {
+                dot = 0;
+                continue;
+            };
+
+            let (byte, index) = match self.reverses() {
+                true => {
+                    let moved = dot;
+                    dot = byte & SegmentBits::Dot as u8;
+
+                    (
+                        (self.transform(byte) & !(SegmentBits::Dot as u8)) | moved,
+                        N - 1 - index,
+                    )
+                }
+                false => (self.transform(byte), index),
+            };
+
+            transformed[index] = Some(byte);
+        }
+
+        transformed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mappings::{DigitBits, SegmentBits};
+
+    use super::*;
+
+    const DOT: u8 = SegmentBits::Dot as u8;
+
+    #[test]
+    fn bytes_are_transformed() {
+        let seven = DigitBits::Seven as u8;
+
+        assert_eq!(Orientation::Normal.transform(seven), seven);
+        assert_eq!(
+            Orientation::Rotated.transform(seven),
+            SegmentBits::SegD as u8 | SegmentBits::SegE as u8 | SegmentBits::SegF as u8
+        );
+        assert_eq!(
+            Orientation::Mirrored.transform(seven),
+            SegmentBits::SegA as u8 | SegmentBits::SegE as u8 | SegmentBits::SegF as u8
+        );
+        assert_eq!(
+            Orientation::Flipped.transform(seven),
+            SegmentBits::SegB as u8 | SegmentBits::SegC as u8 | SegmentBits::SegD as u8
+        );
+    }
+
+    #[test]
+    fn digits_are_reversed() {
+        let digits = [1, 2, 3, 4].map(Some);
+
+        assert_eq!(
+            Orientation::Flipped.apply(digits.map(|d| d.map(flip))),
+            digits
+        );
+        assert_eq!(
+            Orientation::Rotated
+                .apply([None, Some(1), Some(2), Some(3)].map(|d| d.map(flip_mirror))),
+            [Some(3), Some(2), Some(1), None]
+        );
+    }
+
+    #[test]
+    fn dots_stay_after_their_digit() {
+        let one = DigitBits::One as u8;
+        let five = DigitBits::Five as u8;
+
+        // 1.5 on a 4-digit display: the dot moves from the 1 to the 5.
+        let digits = Orientation::Mirrored.apply([0, 0, one | DOT, five].map(Some));
+
+        assert_eq!(
+            digits,
+            [
+                Some(mirror(five) | DOT),
+                Some(mirror(one)),
+                Some(0),
+                Some(0)
+            ]
+        );
+
+        // The dot of the last digit is dropped.
+        let digits = Orientation::Rotated.apply([0, 0, 0, five | DOT].map(Some));
+
+        assert_eq!(digits, [Some(flip_mirror(five)), Some(0), Some(0), Some(0)]);
+
+        // Dots are not moved onto digits that are not written.
+        let digits = Orientation::Rotated.apply([None, Some(one | DOT), None, None]);
+
+        assert_eq!(digits, [None, None, Some(flip_mirror(one)), None]);
+
+        // Flipping keeps the dot on its digit.
+        let digits = Orientation::Flipped.apply([None, None, None, Some(one | DOT)]);
+
+        assert_eq!(digits, [None, None, None, Some(flip(one) | DOT)]);
+    }
+}
@@ -0,0 +1,76 @@
+//! Methods shared by the animation runners.
+
+/// Implements the methods running an animation over its `into_steps` method.
+///
+/// `into_steps` must release the `device` and return the steps of the animation with their delays:
+///
+/// ```text
+/// fn into_steps(self) -> (&'d mut TM1637<..>, impl Iterator<Item = (Step<impl Iterator<Item = u8>>, u32)>)
+/// ```
+///
+/// Invoked inside the `duplicate_item` of the calling module with the name of the animation in the docs,
+/// the number of positions and the `Token`, `async`, `await`, `ScrollIter`, `Signal` and `never` substitutions.
+macro_rules! runner_methods {
+    (
+        $name:literal,
+        $N:tt,
+        [$($Token:tt)+],
+        [$($async:tt)?],
+        [$($await:tt)+],
+        [$($Iter:tt)+],
+        [$($Signal:tt)+],
+        [$($never:tt)+] $(,)?
+    ) => {
+        #[doc = concat!("Return the ", $name, " as an iterator.")]
+        pub fn steps(self) -> impl $($Iter)+<Item = Result<(), $crate::Error<ERR>>> + 'd {
+            self.steps_until($($never)+)
+        }
+
+        #[doc = concat!("Return the ", $name, " as an iterator that ends once the `signal` fires.")]
+        ///
+        /// See [`Self::run_until`].
+        pub fn steps_until(
+            self,
+            signal: $($Signal)+,
+        ) -> impl $($Iter)+<Item = Result<(), $crate::Error<ERR>>> + 'd {
+            let (device, steps) = self.into_steps();
+
+            device.animate_until(steps, signal)
+        }
+
+        #[doc = concat!("Return the ", $name, " as a tick-driven [`Animator`](crate::animator::Animator), releasing the `device`.")]
+        pub fn animator(
+            self,
+        ) -> $crate::animator::Animator<
+            $N,
+            $($Token)+,
+            impl Iterator<Item = ($crate::Step<impl Iterator<Item = u8>>, u32)>,
+        > {
+            $crate::animator::Animator::new(self.into_steps().1)
+        }
+
+        #[doc = concat!("Run the ", $name, " until its steps end and return the number of steps.")]
+        ///
+        /// Endless animations never return, use [`Self::run_until`] to stop them.
+        pub $($async)? fn run(self) -> usize {
+            self.steps().count().$($await)+
+        }
+
+        #[doc = concat!("Run the ", $name, " until the `signal` fires and return the number of steps.")]
+        ///
+        /// In `async` mode, the `signal` is a future that is checked before each step and awaited during the delays.
+        /// Pin it using [`pin!`](::core::pin::pin) if it is not [`Unpin`].
+        /// The `signal` never interrupts a step, so the animation can be stopped, e.g. by a button, between two complete frames.
+        ///
+        /// Dropping the future instead, e.g. in a `select` or on a timeout, abandons the step that is being written.
+        /// The frame may be left partially written and the bus in the middle of a transaction,
+        /// which the driver ends with a STOP condition before its next transaction, see [`TM1637::recover`](crate::TM1637::recover).
+        ///
+        /// In `blocking` mode, the `signal` is a closure that is called before each step.
+        pub $($async)? fn run_until(self, signal: $($Signal)+) -> usize {
+            self.steps_until(signal).count().$($await)+
+        }
+    };
+}
+
+pub(crate) use runner_methods;
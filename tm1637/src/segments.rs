@@ -0,0 +1,100 @@
+//! Custom segment wiring.
+
+/// Lookup table remapping the segments of a byte to the `SEG` pins of the display module.
+///
+/// Use this if the segments of your module are not wired like [`SegmentBits`](crate::mappings::SegmentBits),
+/// so the [`mappings`](crate::mappings) keep working.
+/// The table is applied to every byte just before it is written to the display.
+///
+/// Set using [`TM1637Builder::segments`](crate::TM1637Builder::segments) or [`TM1637::set_segments`](crate::TM1637::set_segments).
+///
+/// # Example
+///
+/// A module with the segments `A` and `B` swapped.
+///
+/// ```rust
+/// use tm1637_embedded_hal::{mock::Noop, mappings::SegmentBits, SegmentMap, TM1637Builder};
+///
+/// static SEGMENTS: SegmentMap = SegmentMap::new([1, 0, 2, 3, 4, 5, 6, 7]);
+///
+/// assert_eq!(SEGMENTS.map(SegmentBits::SegA as u8), SegmentBits::SegB as u8);
+///
+/// let mut tm = TM1637Builder::new(Noop, Noop, Noop)
+///     .segments(&SEGMENTS)
+///     .build_blocking::<4>();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SegmentMap {
+    table: [u8; 256],
+}
+
+impl SegmentMap {
+    /// The segments are wired like [`SegmentBits`](crate::mappings::SegmentBits).
+    pub const IDENTITY: Self = Self::new([0, 1, 2, 3, 4, 5, 6, 7]);
+
+    /// Create a new [`SegmentMap`] from the `SEG` pin of each segment.
+    ///
+    /// `wiring[i]` is the bit, i.e. `SEG` pin minus one, driving the segment at bit `i` of [`SegmentBits`](crate::mappings::SegmentBits),
+    /// from `A` at bit `0` to the dot at bit `7`. Only the lower 3 bits of each pin are used.
+    pub const fn new(wiring: [u8; 8]) -> Self {
+        let mut table = [0; 256];
+        let mut byte = 0;
+
+        while byte < 256 {
+            let mut segment = 0;
+
+            while segment < 8 {
+                if byte & (1 << segment) != 0 {
+                    table[byte] |= 1 << (wiring[segment] & 0x07);
+                }
+
+                segment += 1;
+            }
+
+            byte += 1;
+        }
+
+        Self { table }
+    }
+
+    /// Remap the segments of a `byte`.
+    pub const fn map(&self, byte: u8) -> u8 {
+        self.table[byte as usize]
+    }
+}
+
+impl Default for SegmentMap {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mappings::{DigitBits, SegmentBits};
+
+    use super::*;
+
+    #[test]
+    fn identity_keeps_bytes() {
+        for byte in 0..=255 {
+            assert_eq!(SegmentMap::IDENTITY.map(byte), byte);
+        }
+    }
+
+    #[test]
+    fn segments_are_remapped() {
+        // The dot is wired to SEG1 and A to SEG8.
+        let segments = SegmentMap::new([7, 1, 2, 3, 4, 5, 6, 0]);
+
+        assert_eq!(
+            segments.map(SegmentBits::Dot as u8),
+            SegmentBits::SegA as u8
+        );
+        assert_eq!(
+            segments.map(DigitBits::Seven as u8),
+            SegmentBits::Dot as u8 | SegmentBits::SegB as u8 | SegmentBits::SegC as u8
+        );
+    }
+}
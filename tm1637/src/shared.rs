@@ -0,0 +1,359 @@
+//! Share an `async` [`TM1637`] between tasks.
+
+use ::core::{future::Future, ops::DerefMut};
+use ::embedded_hal::digital::OutputPin;
+
+use crate::{
+    layers::{Compositor, Layers},
+    tokens::Async,
+    ConditionalInputPin, Error, TM1637,
+};
+
+/// An `async` mutex.
+///
+/// Implement this trait for the mutex of your executor, e.g. a newtype around `embassy_sync::mutex::Mutex`.
+pub trait Mutex {
+    /// The protected data.
+    type Data;
+
+    /// Guard giving access to the protected data until dropped.
+    type Guard<'a>: DerefMut<Target = Self::Data>
+    where
+        Self: 'a;
+
+    /// Wait until the mutex is available and lock it.
+    fn lock(&self) -> impl Future<Output = Self::Guard<'_>>;
+}
+
+/// An `async` [`TM1637`] shared between tasks using `L` priority layers.
+///
+/// Put it into a [`Mutex`] and give each task a [`Handle`] to a layer.
+/// The content of the highest layer is shown, e.g. an alert overrides the clock and the clock is restored once the alert is dismissed.
+///
+/// Content shown for a limited time using [`Handle::show_for`] is removed by [`Handle::poll`], see [`Compositor`].
+///
+/// # Example
+///
+/// ```rust
+/// use core::cell::{RefCell, RefMut};
+/// use core::future::{ready, Future};
+///
+/// use tm1637_embedded_hal::{
+///     mappings::UpCharBits,
+///     mock::Noop,
+///     shared::{Handle, Mutex, SharedDisplay},
+///     TM1637Builder,
+/// };
+///
+/// // A mutex for a single-threaded executor, use the mutex of your executor instead.
+/// struct Local<T>(RefCell<T>);
+///
+/// impl<T> Mutex for Local<T> {
+///     type Data = T;
+///     type Guard<'a> = RefMut<'a, T> where T: 'a;
+///
+///     fn lock(&self) -> impl Future<Output = Self::Guard<'_>> {
+///         ready(self.0.borrow_mut())
+///     }
+/// }
+///
+/// const CLOCK: usize = 0;
+/// const ALERT: usize = 1;
+///
+/// let tm = TM1637Builder::new(Noop, Noop, Noop).build_async::<4>();
+/// let display = Local(RefCell::new(SharedDisplay::<4, 2, _, _, _>::new(tm)));
+///
+/// let clock = Handle::new(&display, CLOCK);
+/// let alert = Handle::new(&display, ALERT);
+///
+/// async {
+///     clock.show([0, 0, 0, 0]).await.ok();
+///
+///     // The alert overrides the clock.
+///     alert.show([UpCharBits::UpE as u8, 0, 0, 0]).await.ok();
+///     // The clock is updated in the background.
+///     clock.show([0, 0, 0, 1]).await.ok();
+///     // The clock is shown again.
+///     alert.dismiss().await.ok();
+/// };
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SharedDisplay<const N: usize, const L: usize, CLK, DIO, DELAY> {
+    device: TM1637<N, Async, CLK, DIO, DELAY>,
+    compositor: Compositor<N, L>,
+}
+
+impl<const N: usize, const L: usize, CLK, DIO, DELAY> SharedDisplay<N, L, CLK, DIO, DELAY> {
+    /// Create a new [`SharedDisplay`] without content.
+    pub const fn new(device: TM1637<N, Async, CLK, DIO, DELAY>) -> Self {
+        Self {
+            device,
+            compositor: Compositor::new(),
+        }
+    }
+
+    /// Get a reference to the device.
+    pub const fn device(&self) -> &TM1637<N, Async, CLK, DIO, DELAY> {
+        &self.device
+    }
+
+    /// Get a mutable reference to the device.
+    ///
+    /// Writing to the device directly bypasses the layers until [`SharedDisplay::redraw`] is called.
+    pub const fn device_mut(&mut self) -> &mut TM1637<N, Async, CLK, DIO, DELAY> {
+        &mut self.device
+    }
+
+    /// Get a reference to the layers.
+    pub const fn layers(&self) -> &Layers<N, L> {
+        self.compositor.layers()
+    }
+
+    /// Get the milliseconds from `now_ms` until the next content expires.
+    pub fn deadline(&self, now_ms: u32) -> Option<u32> {
+        self.compositor.deadline(now_ms)
+    }
+
+    /// Split the [`SharedDisplay`] into the device and the compositor.
+    pub fn into_parts(self) -> (TM1637<N, Async, CLK, DIO, DELAY>, Compositor<N, L>) {
+        (self.device, self.compositor)
+    }
+}
+
+/// Handle to a layer of a [`SharedDisplay`] with `N` positions protected by the mutex `M`.
+///
+/// See [`SharedDisplay`].
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Handle<'m, const N: usize, M> {
+    mutex: &'m M,
+    layer: usize,
+}
+
+impl<'m, const N: usize, M> Handle<'m, N, M> {
+    /// Create a new [`Handle`] to the `layer` of the display in the `mutex`.
+    ///
+    /// Layers with higher indices override lower ones.
+    pub const fn new<const L: usize, CLK, DIO, DELAY>(mutex: &'m M, layer: usize) -> Self
+    where
+        M: Mutex<Data = SharedDisplay<N, L, CLK, DIO, DELAY>>,
+    {
+        Self { mutex, layer }
+    }
+
+    /// Get the layer of this handle.
+    pub const fn layer(&self) -> usize {
+        self.layer
+    }
+}
+
+impl<const N: usize, M> Clone for Handle<'_, N, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, M> Copy for Handle<'_, N, M> {}
+
+#[::duplicate::duplicate_item(
+    NUM_POS ;
+    [4] ;
+    [6] ;
+)]
+impl<const L: usize, CLK, DIO, DELAY, ERR> SharedDisplay<NUM_POS, L, CLK, DIO, DELAY>
+where
+    CLK: OutputPin<Error = ERR>,
+    DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
+    DELAY: ::embedded_hal_async::delay::DelayNs,
+{
+    /// Set the content of the `layer` and show it if it is the highest layer.
+    pub async fn show(&mut self, layer: usize, bytes: [u8; NUM_POS]) -> Result<(), Error<ERR>> {
+        self.compositor.set(layer, bytes);
+
+        let bytes = self.compositor.draw();
+
+        self.write(bytes).await
+    }
+
+    /// Set the content of the `layer` for `duration_ms` milliseconds from `now_ms` and show it if it is the highest layer.
+    ///
+    /// The content is removed by [`SharedDisplay::poll`].
+    pub async fn show_for(
+        &mut self,
+        layer: usize,
+        bytes: [u8; NUM_POS],
+        now_ms: u32,
+        duration_ms: u32,
+    ) -> Result<(), Error<ERR>> {
+        self.compositor.set_for(layer, bytes, now_ms, duration_ms);
+
+        let bytes = self.compositor.draw();
+
+        self.write(bytes).await
+    }
+
+    /// Remove the content of the `layer` and show the highest remaining layer if it was shown.
+    ///
+    /// The display is cleared if no layer holds content.
+    pub async fn dismiss(&mut self, layer: usize) -> Result<(), Error<ERR>> {
+        self.compositor.clear(layer);
+
+        let bytes = self.compositor.draw();
+
+        self.write(bytes).await
+    }
+
+    /// Remove the content that expired at `now_ms` and show the highest remaining layer if the shown content changed.
+    pub async fn poll(&mut self, now_ms: u32) -> Result<(), Error<ERR>> {
+        let bytes = self.compositor.poll(now_ms);
+
+        self.write(bytes).await
+    }
+
+    /// Write the content of the highest layer to the display.
+    ///
+    /// The display is cleared if no layer holds content.
+    pub async fn redraw(&mut self) -> Result<(), Error<ERR>> {
+        self.compositor.invalidate();
+
+        let bytes = self.compositor.draw();
+
+        self.write(bytes).await
+    }
+
+    /// Write the `bytes` returned by the compositor, if any.
+    async fn write(&mut self, bytes: Option<[u8; NUM_POS]>) -> Result<(), Error<ERR>> {
+        let Some(bytes) = bytes else {
+            return Ok(());
+        };
+
+        let result = self.device.options().slice(&bytes).display().await;
+
+        if result.is_err() {
+            self.compositor.invalidate();
+        }
+
+        result
+    }
+}
+
+#[::duplicate::duplicate_item(
+    NUM_POS ;
+    [4] ;
+    [6] ;
+)]
+impl<M, const L: usize, CLK, DIO, DELAY, ERR> Handle<'_, NUM_POS, M>
+where
+    M: Mutex<Data = SharedDisplay<NUM_POS, L, CLK, DIO, DELAY>>,
+    CLK: OutputPin<Error = ERR>,
+    DIO: OutputPin<Error = ERR> + ConditionalInputPin<ERR>,
+    DELAY: ::embedded_hal_async::delay::DelayNs,
+{
+    /// Set the content of the layer and show it if it is the highest layer.
+    ///
+    /// See [`SharedDisplay::show`].
+    pub async fn show(&self, bytes: [u8; NUM_POS]) -> Result<(), Error<ERR>> {
+        self.mutex.lock().await.show(self.layer, bytes).await
+    }
+
+    /// Remove the content of the layer, restoring the content of the highest remaining layer.
+    ///
+    /// See [`SharedDisplay::dismiss`].
+    pub async fn dismiss(&self) -> Result<(), Error<ERR>> {
+        self.mutex.lock().await.dismiss(self.layer).await
+    }
+
+    /// Set the content of the layer for `duration_ms` milliseconds from `now_ms` and show it if it is the highest layer.
+    ///
+    /// See [`SharedDisplay::show_for`].
+    pub async fn show_for(
+        &self,
+        bytes: [u8; NUM_POS],
+        now_ms: u32,
+        duration_ms: u32,
+    ) -> Result<(), Error<ERR>> {
+        self.mutex
+            .lock()
+            .await
+            .show_for(self.layer, bytes, now_ms, duration_ms)
+            .await
+    }
+
+    /// Remove the content that expired at `now_ms`, restoring the content of the highest remaining layer.
+    ///
+    /// See [`SharedDisplay::poll`].
+    pub async fn poll(&self, now_ms: u32) -> Result<(), Error<ERR>> {
+        self.mutex.lock().await.poll(now_ms).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::core::{
+        cell::{RefCell, RefMut},
+        future::ready,
+        pin::pin,
+        task::{Context, Poll},
+    };
+    use ::futures::task::noop_waker_ref;
+
+    use super::*;
+    use crate::{mock::Noop, TM1637Builder};
+
+    struct Local<T>(RefCell<T>);
+
+    impl<T> Mutex for Local<T> {
+        type Data = T;
+        type Guard<'a>
+            = RefMut<'a, T>
+        where
+            T: 'a;
+
+        fn lock(&self) -> impl Future<Output = Self::Guard<'_>> {
+            ready(self.0.borrow_mut())
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn alert_overrides_and_restores_the_clock() {
+        let tm = TM1637Builder::new(Noop, Noop, Noop).build_async::<4>();
+        let display = Local(RefCell::new(SharedDisplay::<4, 2, _, _, _>::new(tm)));
+
+        let clock = Handle::new(&display, 0);
+        let alert = Handle::new(&display, 1);
+
+        block_on(async {
+            clock.show([1; 4]).await.ok();
+            alert.show([2; 4]).await.ok();
+            clock.show([3; 4]).await.ok();
+
+            assert_eq!(display.0.borrow().layers().top(), Some((1, [2; 4])));
+
+            alert.dismiss().await.ok();
+
+            assert_eq!(display.0.borrow().layers().top(), Some((0, [3; 4])));
+
+            alert.show_for([4; 4], 1000, 2000).await.ok();
+            clock.poll(2999).await.ok();
+
+            assert_eq!(display.0.borrow().layers().top(), Some((1, [4; 4])));
+            assert_eq!(display.0.borrow().deadline(2999), Some(1));
+
+            clock.poll(3000).await.ok();
+
+            assert_eq!(display.0.borrow().layers().top(), Some((0, [3; 4])));
+        });
+    }
+}
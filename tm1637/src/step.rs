@@ -0,0 +1,70 @@
+use crate::Brightness;
+
+/// A single step of an animation.
+///
+/// Every step is executed by the device and followed by a delay.
+#[derive(Debug, Clone, Copy)]
+pub enum Step<I> {
+    /// Write the bytes to the display starting from the position.
+    Display(usize, I),
+    /// Turn the display on using the current brightness level.
+    On,
+    /// Turn the display off.
+    Off,
+    /// Set the brightness level and write it to the display.
+    Brightness(Brightness),
+}
+
+impl<I> Step<I> {
+    /// Map the position and bytes of a [`Step::Display`] using the provided function.
+    pub(crate) fn map<J>(self, f: impl FnOnce(usize, I) -> (usize, J)) -> Step<J> {
+        match self {
+            Step::Display(position, bytes) => {
+                let (position, bytes) = f(position, bytes);
+
+                Step::Display(position, bytes)
+            }
+            Step::On => Step::On,
+            Step::Off => Step::Off,
+            Step::Brightness(brightness) => Step::Brightness(brightness),
+        }
+    }
+}
+
+/// Iterator over `count` cycles or an endless iterator if `count` is [`None`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct Cycles {
+    count: Option<usize>,
+}
+
+impl Iterator for Cycles {
+    type Item = ();
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.count {
+            Some(0) => None,
+            Some(count) => {
+                *count -= 1;
+
+                Some(())
+            }
+            None => Some(()),
+        }
+    }
+}
+
+/// Returns an iterator over `count` cycles or an endless iterator if `count` is [`None`].
+pub(crate) const fn cycles(count: Option<usize>) -> Cycles {
+    Cycles { count }
+}
+
+/// Returns [`cycles`] over a cycled iterator, which is not cycled forever if it is `empty`.
+///
+/// Flattening endless cycles of an empty iterator would never yield an item.
+pub(crate) const fn cycles_of(count: Option<usize>, empty: bool) -> Cycles {
+    match (count, empty) {
+        (None, true) => cycles(Some(0)),
+        _ => cycles(count),
+    }
+}
@@ -2,12 +2,17 @@
 
 use ::core::str::Bytes;
 
-use crate::mappings::{from_ascii_byte, SegmentBits};
+use crate::{
+    mappings::{from_ascii_byte, SegmentBits},
+    BoardProfile, DotKind,
+};
 
 /// Parse a string to it's corresponding 7-segment display bits.
 ///
 /// Dots are ignored unless they appear after a character. The dot is then or'd with the character.
 ///
+/// Colons are parsed as dots, see [`BoardProfile`]. Use [`StrParser::with_profile`] to drop decimal points that would light a colon.
+///
 /// # Example
 ///
 /// ```rust
@@ -35,6 +40,10 @@ pub struct StrParser<'a> {
     /// We or this value with the found digit on the next call to `next_back`.
     or: u8,
     size: usize,
+    /// Number of bytes returned from the front.
+    front: usize,
+    /// The profile of the display and the position of the first byte on it.
+    profile: Option<(BoardProfile, usize)>,
 }
 
 #[cfg(feature = "defmt")]
@@ -51,7 +60,34 @@ impl<'a> StrParser<'a> {
             bytes: str.bytes(),
             current: None,
             or: 0,
-            size: str.bytes().filter(|byte| *byte != b'.').count(),
+            front: 0,
+            profile: None,
+            size: str
+                .bytes()
+                .filter(|byte| !matches!(byte, b'.' | b':'))
+                .count(),
+        }
+    }
+}
+
+impl StrParser<'_> {
+    /// Drop the decimal points of the bytes that are displayed at a [`DotKind::Colon`] digit of the `profile`,
+    /// starting from `position`.
+    ///
+    /// Colons are kept, so `"23.5"` is shown as `235` and `"12:34"` as `12:34` on a [`BoardProfile::CLOCK`].
+    pub const fn with_profile(mut self, profile: BoardProfile, position: usize) -> Self {
+        self.profile = Some((profile, position));
+        self
+    }
+
+    /// Returns the dot set by the `separator` after the byte at `index`.
+    const fn dot(&self, separator: u8, index: usize) -> u8 {
+        match (separator, self.profile) {
+            (b'.', Some((profile, position))) => match profile.dot(position + index) {
+                DotKind::Colon => 0,
+                _ => SegmentBits::Dot as u8,
+            },
+            _ => SegmentBits::Dot as u8,
         }
     }
 }
@@ -73,17 +109,21 @@ impl Iterator for StrParser<'_> {
         loop {
             match self.bytes.next() {
                 Some(byte) => match byte {
-                    b'.' => match self.current.take() {
+                    separator @ (b'.' | b':') => match self.current.take() {
                         Some(current) => {
+                            let dot = self.dot(separator, self.front);
+
                             self.size -= 1;
+                            self.front += 1;
 
-                            return Some(from_ascii_byte(current) | SegmentBits::Dot as u8);
+                            return Some(from_ascii_byte(current) | dot);
                         }
                         None => continue,
                     },
                     byte => match self.current.replace(byte) {
                         Some(current) => {
                             self.size -= 1;
+                            self.front += 1;
 
                             return Some(from_ascii_byte(current));
                         }
@@ -93,6 +133,7 @@ impl Iterator for StrParser<'_> {
                 None => match self.current.take().map(from_ascii_byte) {
                     Some(current) => {
                         self.size -= 1;
+                        self.front += 1;
 
                         return Some(current);
                     }
@@ -118,8 +159,8 @@ impl DoubleEndedIterator for StrParser<'_> {
         loop {
             match self.bytes.next_back() {
                 Some(byte) => match byte {
-                    b'.' => {
-                        self.or = SegmentBits::Dot as u8;
+                    separator @ (b'.' | b':') => {
+                        self.or = self.dot(separator, self.front + self.size - 1);
 
                         continue;
                     }
@@ -215,6 +256,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn colons() {
+        let parser = StrParser::new("12:34");
+        let result: Vec<u8> = parser.collect();
+
+        assert_eq!(
+            vec![
+                DigitBits::One as u8,
+                DigitBits::Two as u8 | SegmentBits::Dot as u8,
+                DigitBits::Three as u8,
+                DigitBits::Four as u8
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn decimal_points_are_dropped_on_colons() {
+        let parser = StrParser::new("23.5:").with_profile(BoardProfile::CLOCK, 0);
+        let result: Vec<u8> = parser.collect();
+
+        assert_eq!(
+            vec![
+                DigitBits::Two as u8,
+                DigitBits::Three as u8,
+                DigitBits::Five as u8 | SegmentBits::Dot as u8,
+            ],
+            result
+        );
+
+        let parser = StrParser::new("1.2:3").with_profile(BoardProfile::CLOCK, 0);
+        let result: Vec<u8> = parser.rev().collect();
+
+        assert_eq!(
+            vec![
+                DigitBits::Three as u8,
+                DigitBits::Two as u8 | SegmentBits::Dot as u8,
+                DigitBits::One as u8 | SegmentBits::Dot as u8,
+            ],
+            result
+        );
+
+        // The decimal point of the 3 is displayed at the colon.
+        let parser = StrParser::new("3.4").with_profile(BoardProfile::CLOCK, 1);
+        let result: Vec<u8> = parser.collect();
+
+        assert_eq!(vec![DigitBits::Three as u8, DigitBits::Four as u8], result);
+    }
+
     #[test]
     fn no_dots_rev() {
         let parser = StrParser::new("1234");